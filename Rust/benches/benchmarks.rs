@@ -1,13 +1,24 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 extern crate bs;
-use bs::binary_search::power::DynamicImplementation;
-use bs::binary_search::DynamicBinarySearch;
-use bs::helpers::Dynamic;
+use bs::binary_search::batch::DynamicBatchSearch;
+use bs::binary_search::direct::{Buckets, DirectIndex};
+use bs::binary_search::exponential::Cursor;
+use bs::binary_search::eytzinger::{self, utility::eytzinger_layout};
+use bs::binary_search::learned::LearnedIndex;
+use bs::binary_search::power::{DynamicImplementation, StaticImplementation};
+use bs::binary_search::simd;
+use bs::binary_search::stree::{self, utility::stree_layout};
+use bs::binary_search::uniform;
+use bs::binary_search::veb::{self, utility::veb_layout};
+use bs::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+use bs::helpers::{Dynamic, Static};
 
 type DataType = i32;
 type IndexType = usize;
 const N: usize = 65536;
+// Length of arrays, which are too large for caches
+const LARGE: usize = 1 << 24;
 
 fn dynamic_power_search_first(c: &mut Criterion) {
     let bs: DynamicImplementation = DynamicImplementation {};
@@ -61,11 +72,279 @@ fn dynamic_power_fail_last(c: &mut Criterion) {
     });
 }
 
+// Lower bounds of each value and values between them, in array of evenly distributed floats
+fn dynamic_power_lower_bound_float(c: &mut Criterion) {
+    let bs: DynamicImplementation = DynamicImplementation {};
+    let vect: Vec<f64> = Dynamic::filler::<f64, IndexType>(N);
+
+    c.bench_function("dynamic_power_lower_bound_float", |b| {
+        b.iter(|| {
+            for i in 0..(N << 1) {
+                let value: f64 = i as f64;
+                let result: IndexType = bs.lower_bound(black_box(vect.as_slice()), &value);
+                assert!(result == ((i + 1) >> 1));
+            }
+        })
+    });
+}
+
+fn dynamic_direct_lower_bound_float(c: &mut Criterion) {
+    let vect: Vec<f64> = Dynamic::filler::<f64, IndexType>(N);
+    let direct: DirectIndex<f64> = DirectIndex::new(vect.as_slice(), Buckets::Gap(N)).unwrap();
+
+    c.bench_function("dynamic_direct_lower_bound_float", |b| {
+        b.iter(|| {
+            for i in 0..(N << 1) {
+                let value: f64 = i as f64;
+                let result: IndexType = black_box(&direct).lower_bound(&value);
+                assert!(result == ((i + 1) >> 1));
+            }
+        })
+    });
+}
+
+// Lower bounds of each value and values between them, in array of evenly distributed integers
+fn dynamic_lower_bound<B>(c: &mut Criterion, name: &str, bs: &B)
+where
+    B: DynamicBinarySearch<DataType, IndexType>,
+{
+    let vect: Vec<DataType> = Dynamic::filler::<DataType, IndexType>(N);
+
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            for i in 0..(N << 1) {
+                let value: DataType = i as DataType;
+                let result: IndexType = bs.lower_bound(black_box(vect.as_slice()), &value);
+                assert!(result == ((i + 1) >> 1));
+            }
+        })
+    });
+}
+
+fn dynamic_power_lower_bound(c: &mut Criterion) {
+    let bs: DynamicImplementation = DynamicImplementation {};
+    dynamic_lower_bound(c, "dynamic_power_lower_bound", &bs);
+}
+
+fn dynamic_simd_lower_bound(c: &mut Criterion) {
+    let bs: simd::Implementation = simd::Implementation::new();
+    dynamic_lower_bound(c, "dynamic_simd_lower_bound", &bs);
+}
+
+// Sorted pseudo-random 48 bit values, and values to search for
+fn large_filler() -> (Vec<u64>, Vec<u64>) {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || -> u64 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        return state >> 16;
+    };
+    let mut vect: Vec<u64> = (0..LARGE).map(|_| next()).collect();
+    vect.sort_unstable();
+    let values: Vec<u64> = (0..N).map(|_| next()).collect();
+    return (vect, values);
+}
+
+fn dynamic_power_lower_bound_large(c: &mut Criterion) {
+    let (vect, values): (Vec<u64>, Vec<u64>) = large_filler();
+    let bs: DynamicImplementation = DynamicImplementation {};
+
+    c.bench_function("dynamic_power_lower_bound_large", |b| {
+        b.iter(|| {
+            for value in values.iter() {
+                let result: IndexType = bs.lower_bound(black_box(vect.as_slice()), value);
+                assert!(result <= LARGE);
+            }
+        })
+    });
+}
+
+fn dynamic_learned_lower_bound_large(c: &mut Criterion) {
+    let (vect, values): (Vec<u64>, Vec<u64>) = large_filler();
+    let learned: LearnedIndex<u64, DynamicImplementation> =
+        LearnedIndex::new(vect.as_slice(), DynamicImplementation {}, 32).unwrap();
+    println!(
+        "learned index: build time {:?}, {} segments, model size {} bytes, max error {}",
+        learned.build_time(),
+        learned.segments(),
+        learned.model_size(),
+        learned.max_error()
+    );
+
+    c.bench_function("dynamic_learned_lower_bound_large", |b| {
+        b.iter(|| {
+            for value in values.iter() {
+                let result: IndexType = black_box(&learned).lower_bound(value);
+                assert!(result <= LARGE);
+            }
+        })
+    });
+}
+
+// Searches of values in large array, one at a time, or in batches
+fn dynamic_search_large<B>(c: &mut Criterion, name: &str, bs: &B, eytzinger: bool, batch: bool)
+where
+    B: DynamicBatchSearch<u64, IndexType>,
+{
+    let (mut vect, values): (Vec<u64>, Vec<u64>) = large_filler();
+    if eytzinger {
+        let monotonic: Vec<u64> = vect.clone();
+        assert!(eytzinger_layout(&mut vect, &monotonic));
+    }
+    let mut results: Vec<Option<IndexType>> = vec![None; values.len()];
+
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            if batch {
+                bs.search_batch(black_box(vect.as_slice()), &values, &mut results);
+            } else {
+                for (value, result) in values.iter().zip(results.iter_mut()) {
+                    *result = bs.r#impl(black_box(vect.as_slice()), value);
+                }
+            }
+            black_box(&results);
+        })
+    });
+}
+
+fn dynamic_power_search_large(c: &mut Criterion) {
+    let bs: DynamicImplementation = DynamicImplementation {};
+    dynamic_search_large(c, "dynamic_power_search_large", &bs, false, false);
+}
+
+fn dynamic_power_search_batch_large(c: &mut Criterion) {
+    let bs: DynamicImplementation = DynamicImplementation {};
+    dynamic_search_large(c, "dynamic_power_search_batch_large", &bs, false, true);
+}
+
+fn dynamic_eytzinger_search_large(c: &mut Criterion) {
+    let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+    dynamic_search_large(c, "dynamic_eytzinger_search_large", &bs, true, false);
+}
+
+fn dynamic_eytzinger_search_batch_large(c: &mut Criterion) {
+    let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+    dynamic_search_large(c, "dynamic_eytzinger_search_batch_large", &bs, true, true);
+}
+
+// As dynamic_lower_bound, but the values are searched as a stream, from the previous position
+fn dynamic_cursor_lower_bound(c: &mut Criterion) {
+    let vect: Vec<DataType> = Dynamic::filler::<DataType, IndexType>(N);
+
+    c.bench_function("dynamic_cursor_lower_bound", |b| {
+        b.iter(|| {
+            let mut cursor: Cursor<DataType, IndexType, DynamicImplementation> =
+                Cursor::new(black_box(vect.as_slice()), DynamicImplementation {});
+            for i in 0..(N << 1) {
+                let value: DataType = i as DataType;
+                let result: IndexType = cursor.lower_bound(&value);
+                assert!(result == ((i + 1) >> 1));
+            }
+        })
+    });
+}
+
+// As dynamic_lower_bound, but array is in the layout made by given function
+fn dynamic_layout_lower_bound<B>(
+    c: &mut Criterion,
+    name: &str,
+    bs: &B,
+    layout: fn(&mut [DataType], &[DataType]) -> bool,
+) where
+    B: DynamicBinarySearch<DataType, IndexType>,
+{
+    let monotonic: Vec<DataType> = Dynamic::filler::<DataType, IndexType>(N);
+    let mut vect: Vec<DataType> = monotonic.clone();
+    assert!(layout(&mut vect, &monotonic));
+
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            for i in 0..(N << 1) {
+                let value: DataType = i as DataType;
+                let result: IndexType = bs.lower_bound(black_box(vect.as_slice()), &value);
+                assert!(result == ((i + 1) >> 1));
+            }
+        })
+    });
+}
+
+fn dynamic_eytzinger_lower_bound(c: &mut Criterion) {
+    let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+    dynamic_layout_lower_bound(c, "dynamic_eytzinger_lower_bound", &bs, eytzinger_layout);
+}
+
+fn dynamic_stree_lower_bound(c: &mut Criterion) {
+    let bs: stree::Implementation = stree::Implementation {};
+    dynamic_layout_lower_bound(c, "dynamic_stree_lower_bound", &bs, stree_layout);
+}
+
+fn dynamic_veb_lower_bound(c: &mut Criterion) {
+    let bs: veb::Implementation = veb::Implementation {};
+    dynamic_layout_lower_bound(c, "dynamic_veb_lower_bound", &bs, veb_layout);
+}
+
+// Lower bounds of each value and values between them, in array of non-power-of-two length
+fn static_lower_bound<B, const SIZE: usize>(c: &mut Criterion, name: &str, bs: &B)
+where
+    B: StaticBinarySearch<DataType, IndexType, SIZE>,
+{
+    let array: [DataType; SIZE] = Static::filler::<DataType, IndexType, SIZE>();
+
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            for i in 0..(SIZE << 1) {
+                let value: DataType = i as DataType;
+                let result: IndexType = bs.lower_bound(black_box(&array), &value);
+                assert!(result == ((i + 1) >> 1));
+            }
+        })
+    });
+}
+
+fn static_power_lower_bound_511(c: &mut Criterion) {
+    let bs: StaticImplementation = StaticImplementation {};
+    static_lower_bound::<_, 511>(c, "static_power_lower_bound_511", &bs);
+}
+
+fn static_uniform_lower_bound_511(c: &mut Criterion) {
+    let bs: uniform::StaticImplementation = uniform::StaticImplementation {};
+    static_lower_bound::<_, 511>(c, "static_uniform_lower_bound_511", &bs);
+}
+
+fn static_power_lower_bound_513(c: &mut Criterion) {
+    let bs: StaticImplementation = StaticImplementation {};
+    static_lower_bound::<_, 513>(c, "static_power_lower_bound_513", &bs);
+}
+
+fn static_uniform_lower_bound_513(c: &mut Criterion) {
+    let bs: uniform::StaticImplementation = uniform::StaticImplementation {};
+    static_lower_bound::<_, 513>(c, "static_uniform_lower_bound_513", &bs);
+}
+
 criterion_group!(
     benches,
     dynamic_power_search_first,
     dynamic_power_search_last,
     dynamic_power_fail_first,
-    dynamic_power_fail_last
+    dynamic_power_fail_last,
+    dynamic_power_lower_bound_float,
+    dynamic_direct_lower_bound_float,
+    dynamic_power_lower_bound,
+    dynamic_simd_lower_bound,
+    dynamic_cursor_lower_bound,
+    dynamic_eytzinger_lower_bound,
+    dynamic_stree_lower_bound,
+    dynamic_veb_lower_bound,
+    dynamic_power_lower_bound_large,
+    dynamic_learned_lower_bound_large,
+    dynamic_power_search_large,
+    dynamic_power_search_batch_large,
+    dynamic_eytzinger_search_large,
+    dynamic_eytzinger_search_batch_large,
+    static_power_lower_bound_511,
+    static_uniform_lower_bound_511,
+    static_power_lower_bound_513,
+    static_uniform_lower_bound_513
 );
 criterion_main!(benches);
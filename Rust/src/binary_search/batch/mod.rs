@@ -0,0 +1,187 @@
+// Batched searches, for many queries against the same array. A lookup at a time stalls on each
+// cache miss, as the next probe depends on the result of the previous one. Queries of a group are
+// advanced in lockstep instead: the probes of a step are prefetched for every query of the group,
+// and only then compared, so that cache misses of the group overlap. Power search and Eytzinger
+// search take the same number of steps for every query of an array, which makes lockstep simple.
+
+use crate::binary_search::eytzinger::{
+    ImplementationBranchless, ImplementationPrefetching, ImplementationWithoutHints,
+};
+use crate::binary_search::power::DynamicImplementation;
+use crate::binary_search::DynamicBinarySearch;
+
+/// Queries advanced in lockstep
+pub const GROUP: usize = 16;
+
+pub trait DynamicBatchSearch<D, I>: DynamicBinarySearch<D, I>
+where
+    I: num::Integer,
+{
+    /// Results of r#impl for each query; lengths of queries and results must be equal
+    fn search_batch(&self, data: &[D], queries: &[D], results: &mut [Option<I>])
+    where
+        D: std::cmp::PartialOrd;
+}
+
+// Hint to load element at index into cache; index may be out of bounds, as prefetch never faults
+#[inline(always)]
+fn prefetch<D>(data: &[D], index: usize) {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+        unsafe { _mm_prefetch(data.as_ptr().wrapping_add(index) as *const i8, _MM_HINT_T0) };
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = (data, index);
+    }
+}
+
+// Power search of each query; the step widths are the same for every query
+fn power_batch<D, I>(data: &[D], queries: &[D], results: &mut [Option<I>])
+where
+    D: std::cmp::PartialOrd,
+    I: num_traits::NumCast,
+{
+    use crate::binary_search::power::utility::previous_power_of_two;
+    use num_traits::cast::cast;
+
+    assert_eq!(queries.len(), results.len());
+    let n: usize = data.len();
+    if n == 0 {
+        results.fill_with(|| None);
+        return;
+    }
+    let half_power: usize = previous_power_of_two(n);
+    for (queries, results) in queries.chunks(GROUP).zip(results.chunks_mut(GROUP)) {
+        let mut low: [usize; GROUP] = [0; GROUP];
+        let mut width: usize = half_power;
+        while width > 0 {
+            for low in low[..queries.len()].iter() {
+                prefetch(data, low | width);
+            }
+            for q in 0..queries.len() {
+                let mid: usize = low[q] | width;
+                let less_or_equal: bool = (mid < n) && (data[mid] <= queries[q]);
+                low[q] = if less_or_equal { mid } else { low[q] };
+            }
+            width >>= 1;
+        }
+        for q in 0..queries.len() {
+            results[q] = if data[low[q]] == queries[q] {
+                Some(cast(low[q]).unwrap())
+            } else {
+                None
+            };
+        }
+    }
+}
+
+// Eytzinger search of each query; every query takes a step per level, except that the last
+// level may be missing below some nodes
+fn eytzinger_batch<D, I>(data: &[D], queries: &[D], results: &mut [Option<I>])
+where
+    D: std::cmp::PartialOrd,
+    I: num_traits::NumCast,
+{
+    use num_traits::cast::cast;
+
+    assert_eq!(queries.len(), results.len());
+    let n: usize = data.len();
+    let levels: u32 = usize::BITS - n.leading_zeros();
+    for (queries, results) in queries.chunks(GROUP).zip(results.chunks_mut(GROUP)) {
+        let mut index: [usize; GROUP] = [1; GROUP];
+        for _ in 0..levels {
+            for index in index[..queries.len()].iter() {
+                prefetch(data, index - 1);
+            }
+            for q in 0..queries.len() {
+                if index[q] <= n {
+                    index[q] = (index[q] << 1) | (data[index[q] - 1] < queries[q]) as usize;
+                }
+            }
+        }
+        for q in 0..queries.len() {
+            let node: usize = index[q] >> (index[q].trailing_ones() + 1);
+            results[q] = if (node == 0) || (data[node - 1] != queries[q]) {
+                None
+            } else {
+                Some(cast(node - 1).unwrap())
+            };
+        }
+    }
+}
+
+impl<D, I> DynamicBatchSearch<D, I> for DynamicImplementation
+where
+    I: num_traits::Unsigned
+        + num::Integer
+        + num_traits::NumCast
+        + std::marker::Copy
+        + std::ops::BitOr<Output = I>
+        + std::ops::ShrAssign<u16>,
+{
+    #[inline]
+    fn search_batch(&self, data: &[D], queries: &[D], results: &mut [Option<I>])
+    where
+        D: std::cmp::PartialOrd,
+    {
+        power_batch(data, queries, results);
+    }
+}
+
+impl<D, I> DynamicBatchSearch<D, I> for ImplementationWithoutHints
+where
+    I: num_traits::Unsigned
+        + num::Integer
+        + num::PrimInt
+        + std::ops::Not
+        + std::ops::ShlAssign<u16>
+        + std::ops::ShrAssign<u16>
+        + std::ops::BitOrAssign,
+{
+    #[inline]
+    fn search_batch(&self, data: &[D], queries: &[D], results: &mut [Option<I>])
+    where
+        D: std::cmp::PartialOrd,
+    {
+        eytzinger_batch(data, queries, results);
+    }
+}
+
+impl<D, I> DynamicBatchSearch<D, I> for ImplementationBranchless
+where
+    I: num_traits::Unsigned
+        + num::Integer
+        + num::PrimInt
+        + std::ops::Not
+        + std::ops::ShrAssign<u16>
+        + std::convert::From<bool>,
+{
+    #[inline]
+    fn search_batch(&self, data: &[D], queries: &[D], results: &mut [Option<I>])
+    where
+        D: std::cmp::PartialOrd,
+    {
+        eytzinger_batch(data, queries, results);
+    }
+}
+
+impl<D, I> DynamicBatchSearch<D, I> for ImplementationPrefetching
+where
+    I: num_traits::Unsigned
+        + num::Integer
+        + num::PrimInt
+        + std::ops::Not
+        + std::ops::ShrAssign<u16>
+        + std::convert::From<bool>,
+{
+    #[inline]
+    fn search_batch(&self, data: &[D], queries: &[D], results: &mut [Option<I>])
+    where
+        D: std::cmp::PartialOrd,
+    {
+        eytzinger_batch(data, queries, results);
+    }
+}
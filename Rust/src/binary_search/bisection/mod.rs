@@ -0,0 +1,167 @@
+// Bisection over a monotone predicate on a closed range of integers or floats, instead of array.
+// The stepping of range and power search is not reused: both step an unsigned offset from zero by
+// a width, which is at most the length of array and so fits the index type. Width of a closed
+// range, e.g. of i64::MIN..=i64::MAX, does not fit its type, and PrimInt has no unsigned
+// counterpart to step in. So the middle point is floor of average without overflow, and any
+// closed range of the type is valid, e.g. 0..=u64::MAX or i64::MIN..=i64::MAX. Floats are
+// bisected on their bit patterns mapped to integers of same order, which terminates at the
+// exact boundary after at most as many rounds as there are bits.
+
+// Floor of average of low and high; the common bits plus half of the differing ones
+#[inline]
+fn midpoint<T>(low: T, high: T) -> T
+where
+    T: num_traits::PrimInt,
+{
+    return (low & high) + ((low ^ high) >> 1usize);
+}
+
+/// Floats, whose bit patterns can be mapped to unsigned integers of same order
+pub trait OrderedBits: std::marker::Copy {
+    type Bits: num_traits::PrimInt;
+
+    /// Bit pattern, where -inf < -0.0 < +0.0 < +inf as unsigned integers
+    fn to_ordered(self) -> Self::Bits;
+    /// Inverse of 'to_ordered'
+    fn from_ordered(bits: Self::Bits) -> Self;
+}
+
+impl OrderedBits for f32 {
+    type Bits = u32;
+
+    #[inline]
+    fn to_ordered(self) -> u32 {
+        const SIGN: u32 = 1u32 << 31;
+        let bits: u32 = self.to_bits();
+        return if (bits & SIGN) != 0 {
+            !bits
+        } else {
+            bits | SIGN
+        };
+    }
+
+    #[inline]
+    fn from_ordered(bits: u32) -> f32 {
+        const SIGN: u32 = 1u32 << 31;
+        return f32::from_bits(if (bits & SIGN) != 0 {
+            bits & !SIGN
+        } else {
+            !bits
+        });
+    }
+}
+
+impl OrderedBits for f64 {
+    type Bits = u64;
+
+    #[inline]
+    fn to_ordered(self) -> u64 {
+        const SIGN: u64 = 1u64 << 63;
+        let bits: u64 = self.to_bits();
+        return if (bits & SIGN) != 0 {
+            !bits
+        } else {
+            bits | SIGN
+        };
+    }
+
+    #[inline]
+    fn from_ordered(bits: u64) -> f64 {
+        const SIGN: u64 = 1u64 << 63;
+        return f64::from_bits(if (bits & SIGN) != 0 {
+            bits & !SIGN
+        } else {
+            !bits
+        });
+    }
+}
+
+/// The least value of closed range, for which given monotone predicate is true
+///
+/// Predicate must be false for all values before the first true value, and true after it.
+/// Returns none, if range is empty or predicate is false for all of its values.
+pub fn first_true<T, F>(range: std::ops::RangeInclusive<T>, mut pred: F) -> Option<T>
+where
+    T: num_traits::PrimInt,
+    F: FnMut(T) -> bool,
+{
+    let (mut low, mut high): (T, T) = range.into_inner();
+    if (low > high) || !pred(high) {
+        return None;
+    }
+    while low < high {
+        let mid: T = midpoint(low, high);
+        if pred(mid) {
+            high = mid;
+        } else {
+            low = mid + T::one();
+        }
+    }
+    return Some(low);
+}
+
+/// The greatest value of closed range, for which given monotone predicate is true
+///
+/// Predicate must be true for all values before the last true value, and false after it.
+/// Returns none, if range is empty or predicate is false for all of its values.
+pub fn last_true<T, F>(range: std::ops::RangeInclusive<T>, mut pred: F) -> Option<T>
+where
+    T: num_traits::PrimInt,
+    F: FnMut(T) -> bool,
+{
+    let (mut low, mut high): (T, T) = range.into_inner();
+    if (low > high) || !pred(low) {
+        return None;
+    }
+    while low < high {
+        let mid: T = midpoint(low, high) + T::one();
+        if pred(mid) {
+            low = mid;
+        } else {
+            high = mid - T::one();
+        }
+    }
+    return Some(low);
+}
+
+/// Like 'first_true', but for floats; the result is exact, i.e. predicate is false for the
+/// previous float. -0.0 is before +0.0 and NaN as either end of range gives none
+pub fn first_true_float<D, F>(range: std::ops::RangeInclusive<D>, mut pred: F) -> Option<D>
+where
+    D: num_traits::Float + OrderedBits,
+    F: FnMut(D) -> bool,
+{
+    let (low, high): (D, D) = range.into_inner();
+    if low.is_nan() || high.is_nan() {
+        return None;
+    }
+    return first_true(low.to_ordered()..=high.to_ordered(), |bits: D::Bits| {
+        pred(D::from_ordered(bits))
+    })
+    .map(D::from_ordered);
+}
+
+/// Where monotone function crosses zero within closed range of floats
+///
+/// Returns the least value, for which function is zero or has sign opposite to its sign at the
+/// start of range, so the function has not crossed zero at the previous float. Returns none, if
+/// function does not reach zero within range or is NaN at the start of range.
+pub fn zero_crossing<D, G>(range: std::ops::RangeInclusive<D>, mut g: G) -> Option<D>
+where
+    D: num_traits::Float + OrderedBits,
+    G: FnMut(D) -> D,
+{
+    let start: D = g(*range.start());
+    if start.is_nan() {
+        return None;
+    }
+    let increasing: bool = start < D::zero();
+    return first_true_float(range, |x: D| {
+        let y: D = g(x);
+        return if increasing {
+            y >= D::zero()
+        } else {
+            y <= D::zero()
+        };
+    });
+}
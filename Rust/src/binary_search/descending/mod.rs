@@ -0,0 +1,336 @@
+// Adapter for arrays sorted in descending order, like std::cmp::Reverse for the elements.
+//
+// Positions and bound queries follow the order of the array: lower_bound is the position of the
+// first element not greater than value, upper_bound the first element less than value, and
+// partition_point takes a predicate monotone in array order. Comparators of search_by,
+// lower_bound_by and upper_bound_by, and keys of search_by_key, still use the natural ascending
+// order, the adapter reverses them. Value based queries keep their meaning: floor is the greatest
+// element not greater than value and range_indices takes the range of values in natural order,
+// e.g. 2..5.
+//
+// Eytzinger layout of a descending array (built from it by eytzinger_layout) is searched the same
+// way, positions are then ranks in the descending array.
+
+use crate::binary_search::{
+    closest, located_at, located_before, Distance, DynamicBinarySearch, StaticBinarySearch,
+    TieBreak,
+};
+
+/// Searches descending arrays with the wrapped implementation
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Descending<B>(pub B);
+
+// Ordering of element relative to value, when greater elements come first
+#[inline]
+fn reverse_cmp<D>(element: &D, value: &D) -> std::cmp::Ordering
+where
+    D: std::cmp::PartialOrd,
+{
+    use std::cmp::Ordering;
+
+    return if *element > *value {
+        Ordering::Less
+    } else if *element < *value {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    };
+}
+
+impl<D, I, B> DynamicBinarySearch<D, I> for Descending<B>
+where
+    I: num::Integer,
+    B: DynamicBinarySearch<D, I>,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self.search(data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self
+            .0
+            .partition_point(data, |element: &D| *element > *value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self
+            .0
+            .partition_point(data, |element: &D| *element >= *value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self
+            .0
+            .search_by(data, |element: &D| reverse_cmp(element, value));
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return self.0.partition_point(data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], mut f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return self.0.search_by(data, |element: &D| f(element).reverse());
+    }
+
+    #[inline]
+    fn lower_bound_by<F>(&self, data: &[D], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return self
+            .0
+            .lower_bound_by(data, |element: &D| f(element).reverse());
+    }
+
+    #[inline]
+    fn upper_bound_by<F>(&self, data: &[D], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return self
+            .0
+            .upper_bound_by(data, |element: &D| f(element).reverse());
+    }
+
+    fn range_indices<R>(&self, data: &[D], range: R) -> std::ops::Range<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+        R: std::ops::RangeBounds<D>,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+        use std::ops::Bound;
+
+        // Greatest values come first, so the end bound of values gives the start position
+        let start: I = match range.end_bound() {
+            Bound::Included(high) => self.lower_bound(data, high),
+            Bound::Excluded(high) => self.upper_bound(data, high),
+            Bound::Unbounded => cast(0).unwrap(),
+        };
+        let end: I = match range.start_bound() {
+            Bound::Included(low) => self.upper_bound(data, low),
+            Bound::Excluded(low) => self.lower_bound(data, low),
+            Bound::Unbounded => cast(data.len()).unwrap(),
+        };
+        return if start < end {
+            start..end
+        } else {
+            start..start
+        };
+    }
+
+    #[inline]
+    fn location(&self, data: &[D], position: I) -> I {
+        return self.0.location(data, position);
+    }
+
+    // Greatest values come first, so floor is at the lower bound and ceiling before the upper
+    fn floor(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        return located_at(data.len(), lower, |position: I| {
+            self.location(data, position)
+        });
+    }
+
+    fn ceiling(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let upper: I = self.upper_bound(data, value);
+        return located_before(upper, |position: I| self.location(data, position));
+    }
+
+    fn nearest(&self, data: &[D], value: &D, tie: TieBreak) -> Option<I>
+    where
+        D: std::cmp::PartialOrd + Distance + std::marker::Copy,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        let floor: Option<I> = located_at(data.len(), lower, |position: I| {
+            self.location(data, position)
+        });
+        let ceiling: Option<I> = located_before(lower, |position: I| self.location(data, position));
+        return closest(data, value, tie, floor, ceiling);
+    }
+}
+
+impl<D, I, B, const N: usize> StaticBinarySearch<D, I, N> for Descending<B>
+where
+    I: num::Integer,
+    B: StaticBinarySearch<D, I, N>,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self.search(data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self
+            .0
+            .partition_point(data, |element: &D| *element > *value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self
+            .0
+            .partition_point(data, |element: &D| *element >= *value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self
+            .0
+            .search_by(data, |element: &D| reverse_cmp(element, value));
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return self.0.partition_point(data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], mut f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return self.0.search_by(data, |element: &D| f(element).reverse());
+    }
+
+    #[inline]
+    fn lower_bound_by<F>(&self, data: &[D; N], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return self
+            .0
+            .lower_bound_by(data, |element: &D| f(element).reverse());
+    }
+
+    #[inline]
+    fn upper_bound_by<F>(&self, data: &[D; N], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return self
+            .0
+            .upper_bound_by(data, |element: &D| f(element).reverse());
+    }
+
+    fn range_indices<R>(&self, data: &[D; N], range: R) -> std::ops::Range<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+        R: std::ops::RangeBounds<D>,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+        use std::ops::Bound;
+
+        // Greatest values come first, so the end bound of values gives the start position
+        let start: I = match range.end_bound() {
+            Bound::Included(high) => self.lower_bound(data, high),
+            Bound::Excluded(high) => self.upper_bound(data, high),
+            Bound::Unbounded => cast(0).unwrap(),
+        };
+        let end: I = match range.start_bound() {
+            Bound::Included(low) => self.upper_bound(data, low),
+            Bound::Excluded(low) => self.lower_bound(data, low),
+            Bound::Unbounded => cast(N).unwrap(),
+        };
+        return if start < end {
+            start..end
+        } else {
+            start..start
+        };
+    }
+
+    #[inline]
+    fn location(&self, data: &[D; N], position: I) -> I {
+        return self.0.location(data, position);
+    }
+
+    // Greatest values come first, so floor is at the lower bound and ceiling before the upper
+    fn floor(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        return located_at(N, lower, |position: I| self.location(data, position));
+    }
+
+    fn ceiling(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let upper: I = self.upper_bound(data, value);
+        return located_before(upper, |position: I| self.location(data, position));
+    }
+
+    fn nearest(&self, data: &[D; N], value: &D, tie: TieBreak) -> Option<I>
+    where
+        D: std::cmp::PartialOrd + Distance + std::marker::Copy,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        let floor: Option<I> = located_at(N, lower, |position: I| self.location(data, position));
+        let ceiling: Option<I> = located_before(lower, |position: I| self.location(data, position));
+        return closest(data, value, tie, floor, ceiling);
+    }
+}
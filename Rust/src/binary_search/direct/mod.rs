@@ -0,0 +1,220 @@
+// Direct search by Cannizzo, "A Fast and Vectorizable Alternative to Binary Search in O(1) with
+// Wide Applicability to Arrays of Floating Point Numbers". The range of values is split into
+// buckets of equal width, and a precomputed table tells the position of the first element of each
+// bucket. A lookup computes the bucket of value with one subtraction and one multiply, reads the
+// table, and scans the elements of that bucket. Bucket of a value is monotone also with rounding,
+// so results are exact; more buckets only mean fewer elements per bucket to scan. Buckets of more
+// than a few elements, e.g. of clustered values or of a small table, are bisected instead, so that
+// a lookup is never slower than a search of the whole array. NaN is ordered before all elements,
+// like by the predicates of partition_point, so it is found at position 0.
+
+use crate::binary_search::power;
+use crate::binary_search::DynamicBinarySearch;
+
+// The most elements of a bucket scanned one by one
+const LINEAR_FIXUP: usize = 8;
+
+/// The most buckets per element (one for an empty array), whatever the number requested
+pub const MAX_BUCKETS_PER_ELEMENT: usize = 64;
+
+/// Size of the bucket table, i.e. memory spent to shorten the scan within a bucket, at most
+/// MAX_BUCKETS_PER_ELEMENT buckets per element
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Buckets {
+    /// Given number of buckets
+    Fixed(usize),
+    /// Given number of buckets per element
+    PerElement(usize),
+    /// One bucket per the smallest gap between distinct values, but not more than given number;
+    /// about one element per bucket, but rounding may put two into one
+    Gap(usize),
+}
+
+/// Why array was not accepted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirectError {
+    /// Value at index is infinite or not a number
+    NotFinite(usize),
+    /// Value at index is less than the previous one
+    Unsorted(usize),
+}
+
+impl std::fmt::Display for DirectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            DirectError::NotFinite(index) => write!(f, "value {} is not finite", index),
+            DirectError::Unsorted(index) => write!(f, "value {} is not sorted", index),
+        };
+    }
+}
+
+impl std::error::Error for DirectError {}
+
+/// Bucket table of a sorted array of f32 or f64 values; NaN is searched as less than all values
+pub struct DirectIndex<'a, D> {
+    data: &'a [D],
+    first: D,
+    scale: D,
+    last_bucket: usize,
+    table: Vec<usize>,
+    max_fixup: usize,
+}
+
+impl<'a, D> DirectIndex<'a, D>
+where
+    D: num_traits::Float,
+{
+    /// Table for finite values in ascending order
+    pub fn new(data: &'a [D], buckets: Buckets) -> Result<Self, DirectError> {
+        use num_traits::cast::cast;
+
+        for (index, value) in data.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(DirectError::NotFinite(index));
+            }
+            if (index > 0) && (*value < data[index - 1]) {
+                return Err(DirectError::Unsorted(index));
+            }
+        }
+        let count: usize = match buckets {
+            Buckets::Fixed(count) => count,
+            Buckets::PerElement(count) => count.saturating_mul(data.len()),
+            Buckets::Gap(max_count) => Self::gap(data).min(max_count),
+        }
+        .min(data.len().max(1).saturating_mul(MAX_BUCKETS_PER_ELEMENT))
+        .max(1);
+
+        let first: D = *data.first().unwrap_or(&D::zero());
+        let last: D = *data.last().unwrap_or(&D::zero());
+        let two: D = cast(2).unwrap();
+        // Halves, as the width of the range may not be finite, e.g. for -f64::MAX..=f64::MAX
+        let half_width: D = last / two - first / two;
+        let scale: D = if half_width > D::zero() {
+            cast::<usize, D>(count).unwrap() / two / half_width
+        } else {
+            D::zero()
+        };
+        let mut direct: Self = DirectIndex {
+            data,
+            first,
+            scale,
+            last_bucket: count - 1,
+            table: Vec::with_capacity(count.saturating_add(1)),
+            max_fixup: 0,
+        };
+
+        // Position of the first element of each bucket, and the end of the last bucket
+        let mut position: usize = 0;
+        for bucket in 0..count {
+            let start: usize = position;
+            while (position < data.len()) && (direct.bucket(&data[position]) <= bucket) {
+                position += 1;
+            }
+            direct.table.push(start);
+            direct.max_fixup = direct.max_fixup.max(position - start);
+        }
+        direct.table.push(position);
+        return Ok(direct);
+    }
+
+    // Buckets for the smallest gap between distinct values
+    fn gap(data: &[D]) -> usize {
+        use num_traits::cast::cast;
+
+        let gap: Option<D> = data
+            .windows(2)
+            .map(|pair: &[D]| pair[1] - pair[0])
+            .filter(|gap: &D| *gap > D::zero())
+            .fold(None, |min: Option<D>, gap: D| {
+                Some(min.map_or(gap, |min: D| min.min(gap)))
+            });
+        return match gap {
+            Some(gap) => {
+                let width: D = *data.last().unwrap() - *data.first().unwrap();
+                cast::<D, usize>((width / gap).ceil())
+                    .unwrap_or(usize::MAX)
+                    .saturating_add(1)
+            }
+            None => 1,
+        };
+    }
+
+    // Bucket is monotone to value, as subtraction and multiply are monotone also with rounding. NaN
+    // is in the first bucket
+    #[inline]
+    fn bucket(&self, value: &D) -> usize {
+        let index: D = (*value - self.first) * self.scale;
+        return match index.to_usize() {
+            Some(bucket) => bucket.min(self.last_bucket),
+            None if index > D::zero() => self.last_bucket,
+            None => 0,
+        };
+    }
+
+    /// Number of buckets
+    pub fn buckets(&self) -> usize {
+        return self.table.len() - 1;
+    }
+
+    /// The largest number of elements in a bucket, i.e. the longest fixup of a lookup
+    pub fn max_fixup(&self) -> usize {
+        return self.max_fixup;
+    }
+
+    /// Position of the first element that is not less than value
+    #[inline]
+    pub fn lower_bound<I>(&self, value: &D) -> I
+    where
+        I: num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        return cast(self.scan(value, |element: &D| *element < *value)).unwrap();
+    }
+
+    /// Position of the first element that is greater than value
+    #[inline]
+    pub fn upper_bound<I>(&self, value: &D) -> I
+    where
+        I: num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        return cast(self.scan(value, |element: &D| *element <= *value)).unwrap();
+    }
+
+    /// Location of an element equal to value, or position where value could be inserted
+    #[inline]
+    pub fn search<I>(&self, value: &D) -> Result<I, I>
+    where
+        I: num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = self.scan(value, |element: &D| *element < *value);
+        if (position < self.data.len()) && (self.data[position] == *value) {
+            return Ok(cast(position).unwrap());
+        }
+        return Err(cast(position).unwrap());
+    }
+
+    // Elements of earlier buckets are less, and elements of later buckets greater than value
+    #[inline]
+    fn scan<F>(&self, value: &D, mut pred: F) -> usize
+    where
+        F: FnMut(&D) -> bool,
+    {
+        let bucket: usize = self.bucket(value);
+        let mut position: usize = self.table[bucket];
+        let end: usize = self.table[bucket + 1];
+        if end - position > LINEAR_FIXUP {
+            let bs: power::DynamicImplementation = power::DynamicImplementation {};
+            let offset: usize = bs.partition_point(&self.data[position..end], pred);
+            return position + offset;
+        }
+        while (position < end) && pred(&self.data[position]) {
+            position += 1;
+        }
+        return position;
+    }
+}
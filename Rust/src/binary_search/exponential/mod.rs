@@ -0,0 +1,511 @@
+// Exponential (galloping) search probes 1, 2, 4, ... elements away from the chosen end of the
+// array, until the partition point is passed, and then searches the last gap with a bounded
+// binary search. The number of probes is logarithmic to the distance of the partition point from
+// that end, instead of the length of the array.
+// Finger search gallops from a position given by caller instead, to the right or to the left of
+// it, e.g. from the previous result of a sorted stream of queries, and any implementation then
+// searches the window galloped. A stream of m lookups costs O(m log(n / m)) probes, as the
+// distances galloped sum to at most n.
+
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
+
+trait Exponential<D, I>
+where
+    I: num_traits::Unsigned + num::Integer,
+{
+    fn gallop<F>(data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
+}
+
+/// Gallops from the first element, for values near the start of the array
+pub struct FrontImplementation;
+
+/// Gallops from the last element, for values near the end of the array
+pub struct BackImplementation;
+
+// Partition point within [low, high), when it is known not to be outside of it
+#[inline]
+fn bounded<D, F>(data: &[D], mut low: usize, high: usize, pred: &mut F) -> usize
+where
+    F: FnMut(&D) -> bool,
+{
+    let mut width: usize = high - low;
+    while width > 0 {
+        let half: usize = width >> 1;
+        let mid: usize = low + half;
+        if pred(&data[mid]) {
+            low = mid + 1;
+            width -= half + 1;
+        } else {
+            width = half;
+        }
+    }
+    return low;
+}
+
+// Window [low, high] of the partition point, galloping from the first element: predicate is true
+// for all elements before low, and false at high (if within array)
+#[inline]
+fn front_window<D, F>(data: &[D], pred: &mut F) -> (usize, usize)
+where
+    F: FnMut(&D) -> bool,
+{
+    let mut low: usize = 0;
+    let mut high: usize = data.len();
+    let mut width: usize = 1;
+    while width <= high - low {
+        let index: usize = low + width - 1;
+        if pred(&data[index]) {
+            low = index + 1;
+            width <<= 1;
+        } else {
+            high = index;
+            break;
+        }
+    }
+    return (low, high);
+}
+
+// Window [low, high] of the partition point, galloping from the last element: predicate is true
+// at low - 1 (if within array), and false for all elements from high
+#[inline]
+fn back_window<D, F>(data: &[D], pred: &mut F) -> (usize, usize)
+where
+    F: FnMut(&D) -> bool,
+{
+    let mut low: usize = 0;
+    let mut high: usize = data.len();
+    let mut width: usize = 1;
+    while width <= high - low {
+        let index: usize = high - width;
+        if pred(&data[index]) {
+            low = index + 1;
+            break;
+        } else {
+            high = index;
+            width <<= 1;
+        }
+    }
+    return (low, high);
+}
+
+impl<D, I> Exponential<D, I> for FrontImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast,
+{
+    #[inline]
+    fn gallop<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let (low, high): (usize, usize) = front_window(data, &mut pred);
+        return cast(bounded(data, low, high, &mut pred)).unwrap();
+    }
+}
+
+impl<D, I> Exponential<D, I> for BackImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast,
+{
+    #[inline]
+    fn gallop<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let (low, high): (usize, usize) = back_window(data, &mut pred);
+        return cast(bounded(data, low, high, &mut pred)).unwrap();
+    }
+}
+
+impl Layout for FrontImplementation {}
+
+impl<D, I> DynamicBinarySearch<D, I> for FrontImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::gallop(data, |element: &D| *element < *value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::gallop(data, |element: &D| *element <= *value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: I = Self::gallop(data, |element: &D| *element < *value);
+        let index: usize = cast(position).unwrap();
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(position);
+        }
+        return Err(position);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return Self::gallop(data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let position: I = Self::gallop(data, |element: &D| three_way.less(element));
+        let index: usize = cast(position).unwrap();
+        if (index < data.len()) && three_way.found() {
+            return Ok(position);
+        }
+        return Err(position);
+    }
+}
+
+impl Layout for BackImplementation {}
+
+impl<D, I> DynamicBinarySearch<D, I> for BackImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::gallop(data, |element: &D| *element < *value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::gallop(data, |element: &D| *element <= *value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: I = Self::gallop(data, |element: &D| *element < *value);
+        let index: usize = cast(position).unwrap();
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(position);
+        }
+        return Err(position);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return Self::gallop(data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let position: I = Self::gallop(data, |element: &D| three_way.less(element));
+        let index: usize = cast(position).unwrap();
+        if (index < data.len()) && three_way.found() {
+            return Ok(position);
+        }
+        return Err(position);
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for FrontImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::r#impl(self, data, value);
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::lower_bound(self, data, value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::upper_bound(self, data, value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::partition_point(self, data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::search_by(self, data, f);
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for BackImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::r#impl(self, data, value);
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::lower_bound(self, data, value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::upper_bound(self, data, value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::partition_point(self, data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::search_by(self, data, f);
+    }
+}
+
+// Window [low, high] of the partition point, galloped from hint: to the right, when predicate is
+// true at hint, and to the left otherwise
+#[inline]
+fn window_from<D, F>(data: &[D], hint: usize, pred: &mut F) -> (usize, usize)
+where
+    F: FnMut(&D) -> bool,
+{
+    let hint: usize = hint.min(data.len());
+    if (hint < data.len()) && pred(&data[hint]) {
+        let (low, high): (usize, usize) = front_window(&data[hint + 1..], pred);
+        return (hint + 1 + low, hint + 1 + high);
+    }
+    return back_window(&data[..hint], pred);
+}
+
+/// Partition point, galloping from hint and searching the window galloped with bs; hints beyond
+/// the array are at its end. bs must search sorted arrays, as the window is a part of data
+pub fn partition_point_from<D, I, B, F>(bs: &B, data: &[D], hint: I, mut pred: F) -> I
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+    F: FnMut(&D) -> bool,
+{
+    use num_traits::cast::cast;
+
+    let (low, high): (usize, usize) = window_from(data, cast(hint).unwrap(), &mut pred);
+    let position: I = bs.partition_point(&data[low..high], &mut pred);
+    return cast::<usize, I>(low).unwrap() + position;
+}
+
+/// Position of the first element that is not less than value, galloping from hint
+pub fn lower_bound_from<D, I, B>(bs: &B, data: &[D], value: &D, hint: I) -> I
+where
+    D: std::cmp::PartialOrd,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    return partition_point_from(bs, data, hint, |element: &D| *element < *value);
+}
+
+/// Position of the first element that is greater than value, galloping from hint
+pub fn upper_bound_from<D, I, B>(bs: &B, data: &[D], value: &D, hint: I) -> I
+where
+    D: std::cmp::PartialOrd,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    return partition_point_from(bs, data, hint, |element: &D| *element <= *value);
+}
+
+/// Location of an element equal to value, or position where value could be inserted,
+/// galloping from hint
+pub fn search_from<D, I, B>(bs: &B, data: &[D], value: &D, hint: I) -> Result<I, I>
+where
+    D: std::cmp::PartialOrd,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    use num_traits::cast::cast;
+
+    let position: I = lower_bound_from(bs, data, value, hint);
+    let index: usize = cast(position).unwrap();
+    if (index < data.len()) && (data[index] == *value) {
+        return Ok(position);
+    }
+    return Err(position);
+}
+
+/// Finger search of a stream of queries, galloping from the position of the previous query and
+/// searching the window galloped with the wrapped implementation
+pub struct Cursor<'a, D, I, B> {
+    data: &'a [D],
+    bs: B,
+    position: I,
+}
+
+impl<'a, D, I, B> Cursor<'a, D, I, B>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    /// Cursor at the start of data, whose length must fit into I
+    pub fn new(data: &'a [D], bs: B) -> Self {
+        use num_traits::cast::cast;
+
+        assert!(cast::<usize, I>(data.len()).is_some());
+        return Cursor {
+            data,
+            bs,
+            position: I::zero(),
+        };
+    }
+
+    /// Position of the previous query, or where the cursor was moved to
+    pub fn position(&self) -> I {
+        return self.position;
+    }
+
+    /// Moves cursor, e.g. to where the next query is expected
+    pub fn seek(&mut self, position: I) {
+        self.position = position;
+    }
+
+    /// Position of the first element that is not less than value
+    pub fn lower_bound(&mut self, value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        self.position = lower_bound_from(&self.bs, self.data, value, self.position);
+        return self.position;
+    }
+
+    /// Position of the first element that is greater than value
+    pub fn upper_bound(&mut self, value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        self.position = upper_bound_from(&self.bs, self.data, value, self.position);
+        return self.position;
+    }
+
+    /// Location of an element equal to value, or position where value could be inserted
+    pub fn search(&mut self, value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        let result: Result<I, I> = search_from(&self.bs, self.data, value, self.position);
+        self.position = result.unwrap_or_else(|position: I| position);
+        return result;
+    }
+}
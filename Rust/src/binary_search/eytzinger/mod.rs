@@ -1,11 +1,12 @@
 pub mod utility {
+    use crate::binary_search::power::utility::log2;
     use num::PrimInt;
 
     //
     // Some helper functions
     //
 
-    fn recursive_eytzinger_layout<D: std::marker::Copy>(
+    fn recursive_eytzinger_layout<D: std::clone::Clone>(
         eytzinger: &mut [D],
         monotonic: &[D],
         mut i: usize,
@@ -13,7 +14,7 @@ pub mod utility {
     ) -> usize {
         if k <= eytzinger.len() {
             i = recursive_eytzinger_layout(eytzinger, monotonic, i, k << 1usize);
-            eytzinger[k - 1] = monotonic[i];
+            eytzinger[k - 1] = monotonic[i].clone();
             i += 1usize;
             i = recursive_eytzinger_layout(eytzinger, monotonic, i, (k << 1usize) + 1usize);
         }
@@ -22,7 +23,7 @@ pub mod utility {
     }
 
     #[inline]
-    pub fn eytzinger_layout<D: std::marker::Copy>(eytzinger: &mut [D], monotonic: &[D]) -> bool {
+    pub fn eytzinger_layout<D: std::clone::Clone>(eytzinger: &mut [D], monotonic: &[D]) -> bool {
         if eytzinger.len() != monotonic.len() {
             return false;
         }
@@ -32,6 +33,37 @@ pub mod utility {
         return length == monotonic.len();
     }
 
+    // Rank (index in monotonic array) of element at given index of Eytzinger layout of n elements
+    #[inline]
+    pub fn eytzinger_rank(n: usize, index: usize) -> usize {
+        let height: u32 = log2(n).unwrap();
+        let last_level: usize = n + 1 - (1usize << height);
+        let node: usize = index + 1;
+        let depth: u32 = log2(node).unwrap();
+        // In-order position (one based) within perfect tree of same height
+        let position: usize = (((node - (1usize << depth)) << 1) + 1) << (height - depth);
+        // Skip the leaves missing from the last level
+        let missing: usize = (position >> 1).saturating_sub(last_level);
+        return position - missing - 1usize;
+    }
+
+    // Index in Eytzinger layout of n elements for given rank (index in monotonic array)
+    #[inline]
+    pub fn eytzinger_index(n: usize, rank: usize) -> usize {
+        let height: u32 = log2(n).unwrap();
+        let last_level: usize = n + 1 - (1usize << height);
+        let ordinal: usize = rank + 1;
+        // In-order position (one based) within perfect tree of same height
+        let position: usize = if ordinal <= (last_level << 1) {
+            ordinal
+        } else {
+            (ordinal << 1) - (last_level << 1)
+        };
+        let zeros: u32 = position.trailing_zeros();
+        let node: usize = (1usize << (height - zeros)) + (position >> (zeros + 1));
+        return node - 1usize;
+    }
+
     #[inline]
     pub fn ffs<I: PrimInt>(value: I) -> u16 {
         use num_traits::cast::cast;
@@ -43,18 +75,41 @@ pub mod utility {
     }
 }
 
-use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
 
 trait Eytzinger<D, I>
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
         + std::ops::Not
         + std::ops::ShrAssign<u16>,
 {
-    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I>;
+    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn eytzinger_layout_partition<F>(data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
+}
+
+// Convert node (one based, zero if none) found by partition to a rank
+#[inline]
+fn rank_of_node<I>(n: usize, node: I) -> I
+where
+    I: num::PrimInt,
+{
+    use num_traits::cast::cast;
+
+    return if node == cast(0).unwrap() {
+        cast(n).unwrap()
+    } else {
+        cast(utility::eytzinger_rank(
+            n,
+            cast::<I, usize>(node).unwrap() - 1,
+        ))
+        .unwrap()
+    };
 }
 
 pub struct ImplementationWithoutHints;
@@ -63,7 +118,6 @@ pub struct ImplementationPrefetching;
 
 impl<D, I> Eytzinger<D, I> for ImplementationWithoutHints
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -73,7 +127,10 @@ where
         + std::ops::BitOrAssign,
 {
     #[inline]
-    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I> {
+    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let n: I = cast(data.len()).unwrap(); //< This fails, if data length don't fit into type I
@@ -95,11 +152,31 @@ where
             Some(index - cast(1).unwrap())
         };
     }
+
+    #[inline]
+    fn eytzinger_layout_partition<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let n: I = cast(data.len()).unwrap(); //< This fails, if data length don't fit into type I
+        let mut index: I = cast(1).unwrap();
+        while index <= n {
+            if pred(&data[cast::<I, usize>(index).unwrap() - 1]) {
+                index <<= 1;
+                index |= cast(1).unwrap();
+            } else {
+                index <<= 1;
+            }
+        }
+        index >>= utility::ffs(!index);
+        return index;
+    }
 }
 
 impl<D, I> Eytzinger<D, I> for ImplementationBranchless
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -108,7 +185,10 @@ where
         + std::convert::From<bool>,
 {
     #[inline]
-    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I> {
+    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let n: I = cast(data.len()).unwrap(); //< This fails, if data length don't fit into type I
@@ -125,11 +205,26 @@ where
             Some(index - cast(1).unwrap())
         };
     }
+
+    #[inline]
+    fn eytzinger_layout_partition<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let n: I = cast(data.len()).unwrap(); //< This fails, if data length don't fit into type I
+        let mut index: I = cast(1).unwrap();
+        while index <= n {
+            index = (index << 1) | pred(&data[cast::<I, usize>(index).unwrap() - 1]).into();
+        }
+        index >>= utility::ffs(!index);
+        return index;
+    }
 }
 
 impl<D, I> Eytzinger<D, I> for ImplementationPrefetching
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -138,7 +233,10 @@ where
         + std::convert::From<bool>,
 {
     #[inline]
-    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I> {
+    fn eytzinger_layout_search(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
         use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T2};
         use std::ptr;
@@ -149,7 +247,9 @@ where
         while index <= n {
             unsafe {
                 _mm_prefetch(
-                    ptr::addr_of!(data[cast::<I, usize>(index << 1).unwrap()]) as *const i8,
+                    data.as_ptr()
+                        .wrapping_add(cast::<I, usize>(index << 1).unwrap())
+                        as *const i8,
                     _MM_HINT_T2,
                 )
             };
@@ -164,11 +264,44 @@ where
             Some(index - cast(1).unwrap())
         };
     }
+
+    #[inline]
+    fn eytzinger_layout_partition<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+        use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T2};
+
+        let n: I = cast(data.len()).unwrap(); //< This fails, if data length don't fit into type I
+        let mut index: I = cast(1).unwrap();
+        unsafe { _mm_prefetch(data.as_ptr() as *const i8, _MM_HINT_T2) };
+        while index <= n {
+            unsafe {
+                _mm_prefetch(
+                    data.as_ptr()
+                        .wrapping_add(cast::<I, usize>(index << 1).unwrap())
+                        as *const i8,
+                    _MM_HINT_T2,
+                )
+            };
+            index = (index << 1) | pred(&data[cast::<I, usize>(index).unwrap() - 1]).into();
+        }
+        index >>= utility::ffs(!index);
+        return index;
+    }
+}
+
+impl Layout for ImplementationWithoutHints {
+    fn layout<D: std::clone::Clone>(&self, monotonic: &[D]) -> Vec<D> {
+        let mut eytzinger: Vec<D> = monotonic.to_vec();
+        assert!(utility::eytzinger_layout(&mut eytzinger, monotonic));
+        return eytzinger;
+    }
 }
 
 impl<D, I> DynamicBinarySearch<D, I> for ImplementationWithoutHints
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -178,18 +311,118 @@ where
         + std::ops::BitOrAssign,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if data.len() > 0 {
             Self::eytzinger_layout_search(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(
+                data.len(),
+                Self::eytzinger_layout_partition(data, |element: &D| *element < *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(
+                data.len(),
+                Self::eytzinger_layout_partition(data, |element: &D| *element <= *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let node: I = Self::eytzinger_layout_partition(data, |element: &D| *element < *value);
+            if (node != cast(0).unwrap()) && (data[cast::<I, usize>(node).unwrap() - 1] == *value) {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(data.len(), node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(data.len(), Self::eytzinger_layout_partition(data, pred))
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let node: I =
+                Self::eytzinger_layout_partition(data, |element: &D| three_way.less(element));
+            if (node != cast(0).unwrap()) && three_way.found() {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(data.len(), node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, data: &[D], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::eytzinger_index(
+            data.len(),
+            cast(position).unwrap(),
+        ))
+        .unwrap();
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for ImplementationWithoutHints
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -199,18 +432,122 @@ where
         + std::ops::BitOrAssign,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if N > 0 {
             Self::eytzinger_layout_search(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(
+                N,
+                Self::eytzinger_layout_partition(data, |element: &D| *element < *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(
+                N,
+                Self::eytzinger_layout_partition(data, |element: &D| *element <= *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let node: I = Self::eytzinger_layout_partition(data, |element: &D| *element < *value);
+            if (node != cast(0).unwrap()) && (data[cast::<I, usize>(node).unwrap() - 1] == *value) {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(N, node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(N, Self::eytzinger_layout_partition(data, pred))
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let node: I =
+                Self::eytzinger_layout_partition(data, |element: &D| three_way.less(element));
+            if (node != cast(0).unwrap()) && three_way.found() {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(N, node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, _data: &[D; N], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::eytzinger_index(N, cast(position).unwrap())).unwrap();
+    }
+}
+
+impl Layout for ImplementationBranchless {
+    fn layout<D: std::clone::Clone>(&self, monotonic: &[D]) -> Vec<D> {
+        let mut eytzinger: Vec<D> = monotonic.to_vec();
+        assert!(utility::eytzinger_layout(&mut eytzinger, monotonic));
+        return eytzinger;
+    }
 }
 
 impl<D, I> DynamicBinarySearch<D, I> for ImplementationBranchless
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -219,18 +556,118 @@ where
         + std::convert::From<bool>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if data.len() > 0 {
             Self::eytzinger_layout_search(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(
+                data.len(),
+                Self::eytzinger_layout_partition(data, |element: &D| *element < *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(
+                data.len(),
+                Self::eytzinger_layout_partition(data, |element: &D| *element <= *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let node: I = Self::eytzinger_layout_partition(data, |element: &D| *element < *value);
+            if (node != cast(0).unwrap()) && (data[cast::<I, usize>(node).unwrap() - 1] == *value) {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(data.len(), node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(data.len(), Self::eytzinger_layout_partition(data, pred))
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let node: I =
+                Self::eytzinger_layout_partition(data, |element: &D| three_way.less(element));
+            if (node != cast(0).unwrap()) && three_way.found() {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(data.len(), node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, data: &[D], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::eytzinger_index(
+            data.len(),
+            cast(position).unwrap(),
+        ))
+        .unwrap();
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for ImplementationBranchless
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -239,18 +676,122 @@ where
         + std::convert::From<bool>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if N > 0 {
             Self::eytzinger_layout_search(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(
+                N,
+                Self::eytzinger_layout_partition(data, |element: &D| *element < *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(
+                N,
+                Self::eytzinger_layout_partition(data, |element: &D| *element <= *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let node: I = Self::eytzinger_layout_partition(data, |element: &D| *element < *value);
+            if (node != cast(0).unwrap()) && (data[cast::<I, usize>(node).unwrap() - 1] == *value) {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(N, node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(N, Self::eytzinger_layout_partition(data, pred))
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let node: I =
+                Self::eytzinger_layout_partition(data, |element: &D| three_way.less(element));
+            if (node != cast(0).unwrap()) && three_way.found() {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(N, node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, _data: &[D; N], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::eytzinger_index(N, cast(position).unwrap())).unwrap();
+    }
+}
+
+impl Layout for ImplementationPrefetching {
+    fn layout<D: std::clone::Clone>(&self, monotonic: &[D]) -> Vec<D> {
+        let mut eytzinger: Vec<D> = monotonic.to_vec();
+        assert!(utility::eytzinger_layout(&mut eytzinger, monotonic));
+        return eytzinger;
+    }
 }
 
 impl<D, I> DynamicBinarySearch<D, I> for ImplementationPrefetching
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -259,18 +800,118 @@ where
         + std::convert::From<bool>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if data.len() > 0 {
             Self::eytzinger_layout_search(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(
+                data.len(),
+                Self::eytzinger_layout_partition(data, |element: &D| *element < *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(
+                data.len(),
+                Self::eytzinger_layout_partition(data, |element: &D| *element <= *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let node: I = Self::eytzinger_layout_partition(data, |element: &D| *element < *value);
+            if (node != cast(0).unwrap()) && (data[cast::<I, usize>(node).unwrap() - 1] == *value) {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(data.len(), node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            rank_of_node(data.len(), Self::eytzinger_layout_partition(data, pred))
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let node: I =
+                Self::eytzinger_layout_partition(data, |element: &D| three_way.less(element));
+            if (node != cast(0).unwrap()) && three_way.found() {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(data.len(), node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, data: &[D], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::eytzinger_index(
+            data.len(),
+            cast(position).unwrap(),
+        ))
+        .unwrap();
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for ImplementationPrefetching
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num::PrimInt
@@ -279,11 +920,108 @@ where
         + std::convert::From<bool>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if N > 0 {
             Self::eytzinger_layout_search(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(
+                N,
+                Self::eytzinger_layout_partition(data, |element: &D| *element < *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(
+                N,
+                Self::eytzinger_layout_partition(data, |element: &D| *element <= *value),
+            )
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let node: I = Self::eytzinger_layout_partition(data, |element: &D| *element < *value);
+            if (node != cast(0).unwrap()) && (data[cast::<I, usize>(node).unwrap() - 1] == *value) {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(N, node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            rank_of_node(N, Self::eytzinger_layout_partition(data, pred))
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let node: I =
+                Self::eytzinger_layout_partition(data, |element: &D| three_way.less(element));
+            if (node != cast(0).unwrap()) && three_way.found() {
+                return Ok(node - cast(1).unwrap());
+            }
+            return Err(rank_of_node(N, node));
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, _data: &[D; N], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::eytzinger_index(N, cast(position).unwrap())).unwrap();
+    }
 }
@@ -0,0 +1,175 @@
+// Float-aware searches. PartialOrd gives no ordering for NaN, so plain searches over floats silently
+// return arbitrary results when NaN is in the data or is the searched value. These searches
+// compare with an explicit NaN policy instead. Data must be sorted with the same policy, e.g. by
+// NanPolicy::sort.
+//
+// Results for the special values:
+//  - Reject: searching for NaN fails with NanError, data must not contain NaN. -0.0 and +0.0
+//    are equal, so searching for either one may locate the other.
+//  - First / Last: all NaNs, regardless of sign and payload, are equal to each other and ordered
+//    before / after every number. -0.0 and +0.0 are equal.
+//  - TotalOrder: IEEE 754 totalOrder, i.e. f64::total_cmp. Negative NaNs are ordered before
+//    -inf and positive NaNs after +inf, -0.0 is less than +0.0. NaN is found only when an element
+//    with identical bit pattern exists.
+
+use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+
+/// How NaN is ordered relative to numbers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// NaN is not allowed
+    Reject,
+    /// NaNs are ordered before all numbers
+    First,
+    /// NaNs are ordered after all numbers
+    Last,
+    /// IEEE 754 totalOrder, which also orders -0.0 before +0.0
+    TotalOrder,
+}
+
+/// NaN was given while NanPolicy::Reject is in effect
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NanError;
+
+impl std::fmt::Display for NanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return write!(f, "NaN is rejected by the NaN policy");
+    }
+}
+
+impl std::error::Error for NanError {}
+
+impl NanPolicy {
+    /// Ordering of a relative to b. With Reject, NaNs are ordered like with Last
+    pub fn compare<D>(self, a: &D, b: &D) -> std::cmp::Ordering
+    where
+        D: num_traits::Float + num_traits::float::TotalOrder,
+    {
+        use std::cmp::Ordering;
+
+        if self == NanPolicy::TotalOrder {
+            return a.total_cmp(b);
+        }
+        let nan_first: bool = self == NanPolicy::First;
+        return match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) if nan_first => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, true) if nan_first => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(b).unwrap(),
+        };
+    }
+
+    /// Sorts data to the order used by this policy
+    pub fn sort<D>(self, data: &mut [D]) -> Result<(), NanError>
+    where
+        D: num_traits::Float + num_traits::float::TotalOrder,
+    {
+        self.check(data)?;
+        data.sort_by(|a: &D, b: &D| self.compare(a, b));
+        return Ok(());
+    }
+
+    fn check<D>(self, values: &[D]) -> Result<(), NanError>
+    where
+        D: num_traits::Float,
+    {
+        if (self == NanPolicy::Reject) && values.iter().any(|value: &D| value.is_nan()) {
+            return Err(NanError);
+        }
+        return Ok(());
+    }
+}
+
+pub trait DynamicFloatSearch<D, I>: DynamicBinarySearch<D, I>
+where
+    D: num_traits::Float + num_traits::float::TotalOrder,
+    I: num::Integer,
+{
+    /// Location of an element equal to value, or position where value could be inserted
+    fn search_float(
+        &self,
+        data: &[D],
+        value: &D,
+        policy: NanPolicy,
+    ) -> Result<Result<I, I>, NanError>
+    where
+        Self: Sized,
+    {
+        policy.check(std::slice::from_ref(value))?;
+        return Ok(self.search_by(data, |element: &D| policy.compare(element, value)));
+    }
+
+    /// Position of the first element that is not less than value
+    fn lower_bound_float(&self, data: &[D], value: &D, policy: NanPolicy) -> Result<I, NanError>
+    where
+        Self: Sized,
+    {
+        policy.check(std::slice::from_ref(value))?;
+        return Ok(self.lower_bound_by(data, |element: &D| policy.compare(element, value)));
+    }
+
+    /// Position of the first element that is greater than value
+    fn upper_bound_float(&self, data: &[D], value: &D, policy: NanPolicy) -> Result<I, NanError>
+    where
+        Self: Sized,
+    {
+        policy.check(std::slice::from_ref(value))?;
+        return Ok(self.upper_bound_by(data, |element: &D| policy.compare(element, value)));
+    }
+}
+
+impl<D, I, B> DynamicFloatSearch<D, I> for B
+where
+    D: num_traits::Float + num_traits::float::TotalOrder,
+    I: num::Integer,
+    B: DynamicBinarySearch<D, I>,
+{
+}
+
+pub trait StaticFloatSearch<D, I, const N: usize>: StaticBinarySearch<D, I, N>
+where
+    D: num_traits::Float + num_traits::float::TotalOrder,
+    I: num::Integer,
+{
+    /// Location of an element equal to value, or position where value could be inserted
+    fn search_float(
+        &self,
+        data: &[D; N],
+        value: &D,
+        policy: NanPolicy,
+    ) -> Result<Result<I, I>, NanError>
+    where
+        Self: Sized,
+    {
+        policy.check(std::slice::from_ref(value))?;
+        return Ok(self.search_by(data, |element: &D| policy.compare(element, value)));
+    }
+
+    /// Position of the first element that is not less than value
+    fn lower_bound_float(&self, data: &[D; N], value: &D, policy: NanPolicy) -> Result<I, NanError>
+    where
+        Self: Sized,
+    {
+        policy.check(std::slice::from_ref(value))?;
+        return Ok(self.lower_bound_by(data, |element: &D| policy.compare(element, value)));
+    }
+
+    /// Position of the first element that is greater than value
+    fn upper_bound_float(&self, data: &[D; N], value: &D, policy: NanPolicy) -> Result<I, NanError>
+    where
+        Self: Sized,
+    {
+        policy.check(std::slice::from_ref(value))?;
+        return Ok(self.upper_bound_by(data, |element: &D| policy.compare(element, value)));
+    }
+}
+
+impl<D, I, B, const N: usize> StaticFloatSearch<D, I, N> for B
+where
+    D: num_traits::Float + num_traits::float::TotalOrder,
+    I: num::Integer,
+    B: StaticBinarySearch<D, I, N>,
+{
+}
@@ -0,0 +1,400 @@
+// Interpolation search guesses the position of a value from the values at the ends of the
+// searched range, assuming the values are evenly distributed, e.g. auto-increment keys with small
+// gaps. Then the number of probes is O(log log n), but for unevenly distributed values it can be
+// O(n). Elements must convert to numbers (num_traits::ToPrimitive). Searches without a value, i.e.
+// partition_point and search_by, bisect like 'power' does.
+
+use crate::binary_search::power::utility::previous_power_of_two;
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
+
+/// Number of guesses, which do not halve the searched range, before falling back to bisection
+pub const BAD_GUESSES: u32 = 2;
+
+trait Interpolation<D, I>
+where
+    I: num_traits::Unsigned + num::Integer,
+{
+    fn partition<F>(data: &[D], value: &D, pred: F) -> I
+    where
+        D: std::cmp::PartialOrd + num_traits::ToPrimitive,
+        F: FnMut(&D) -> bool;
+}
+
+/// Interpolates, until guesses stop halving the searched range, and then bisects; O(log n)
+pub struct GuardedImplementation;
+
+/// Interpolates once and then scans sequentially; for evenly distributed values with small gaps
+pub struct SequentialImplementation;
+
+// Position within [low, high), where value would be, if values were evenly distributed
+#[inline]
+fn guess<D>(data: &[D], low: usize, high: usize, value: &D) -> usize
+where
+    D: num_traits::ToPrimitive,
+{
+    let last: usize = high - 1;
+    return match (data[low].to_f64(), data[last].to_f64(), value.to_f64()) {
+        (Some(first), Some(end), Some(value)) => {
+            let offset: f64 = (value - first) / (end - first) * (last - low) as f64;
+            // Saturating conversion, also NaN to zero
+            low + std::cmp::min(offset as usize, last - low)
+        }
+        _ => low + ((last - low) >> 1),
+    };
+}
+
+// Partition point within [low, high) like 'power' with bound check
+#[inline]
+fn bisect<D, F>(data: &[D], low: usize, high: usize, mut pred: F) -> usize
+where
+    F: FnMut(&D) -> bool,
+{
+    if low == high {
+        return low;
+    }
+    let range: &[D] = &data[low..high];
+    let mut position: usize = 0;
+    let mut width: usize = previous_power_of_two(range.len());
+    while width > 0 {
+        let mid: usize = position | width;
+        if (mid < range.len()) && pred(&range[mid]) {
+            position = mid;
+        }
+        width >>= 1;
+    }
+    if pred(&range[position]) {
+        return low + position + 1;
+    }
+    return low + position;
+}
+
+impl<D, I> Interpolation<D, I> for GuardedImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast,
+{
+    #[inline]
+    fn partition<F>(data: &[D], value: &D, mut pred: F) -> I
+    where
+        D: std::cmp::PartialOrd + num_traits::ToPrimitive,
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        // Predicate is true for all elements before low, and false for all elements from high
+        let mut low: usize = 0;
+        let mut high: usize = data.len();
+        let mut bad_guesses: u32 = 0;
+        while (low < high) && (bad_guesses < BAD_GUESSES) {
+            if !pred(&data[low]) {
+                return cast(low).unwrap();
+            }
+            if pred(&data[high - 1]) {
+                return cast(high).unwrap();
+            }
+            // Now the values at the ends differ, and value is between them
+            let width: usize = high - low;
+            let index: usize = guess(data, low, high, value);
+            if pred(&data[index]) {
+                low = index + 1;
+            } else {
+                high = index;
+            }
+            if (high - low) > (width >> 1) {
+                bad_guesses += 1;
+            }
+        }
+        return cast(bisect(data, low, high, pred)).unwrap();
+    }
+}
+
+impl<D, I> Interpolation<D, I> for SequentialImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast,
+{
+    #[inline]
+    fn partition<F>(data: &[D], value: &D, mut pred: F) -> I
+    where
+        D: std::cmp::PartialOrd + num_traits::ToPrimitive,
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() == 0 {
+            return cast(0).unwrap();
+        }
+        let mut index: usize = guess(data, 0, data.len(), value);
+        if pred(&data[index]) {
+            index += 1;
+            while (index < data.len()) && pred(&data[index]) {
+                index += 1;
+            }
+        } else {
+            while (index > 0) && !pred(&data[index - 1]) {
+                index -= 1;
+            }
+        }
+        return cast(index).unwrap();
+    }
+}
+
+impl Layout for GuardedImplementation {}
+
+impl<D, I> DynamicBinarySearch<D, I> for GuardedImplementation
+where
+    D: num_traits::ToPrimitive,
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::partition(data, value, |element: &D| *element < *value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::partition(data, value, |element: &D| *element <= *value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: I = Self::partition(data, value, |element: &D| *element < *value);
+        let index: usize = cast(position).unwrap();
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(position);
+        }
+        return Err(position);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return cast(bisect(data, 0, data.len(), pred)).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let index: usize = bisect(data, 0, data.len(), |element: &D| three_way.less(element));
+        if (index < data.len()) && three_way.found() {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+}
+
+impl Layout for SequentialImplementation {}
+
+impl<D, I> DynamicBinarySearch<D, I> for SequentialImplementation
+where
+    D: num_traits::ToPrimitive,
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::partition(data, value, |element: &D| *element < *value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return Self::partition(data, value, |element: &D| *element <= *value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: I = Self::partition(data, value, |element: &D| *element < *value);
+        let index: usize = cast(position).unwrap();
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(position);
+        }
+        return Err(position);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return cast(bisect(data, 0, data.len(), pred)).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let index: usize = bisect(data, 0, data.len(), |element: &D| three_way.less(element));
+        if (index < data.len()) && three_way.found() {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for GuardedImplementation
+where
+    D: num_traits::ToPrimitive,
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::r#impl(self, data, value);
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::lower_bound(self, data, value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::upper_bound(self, data, value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::partition_point(self, data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::search_by(self, data, f);
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for SequentialImplementation
+where
+    D: num_traits::ToPrimitive,
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::r#impl(self, data, value);
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::lower_bound(self, data, value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::upper_bound(self, data, value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::partition_point(self, data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::search_by(self, data, f);
+    }
+}
@@ -0,0 +1,132 @@
+// Lookup of the interval containing a point, over sorted and disjoint inclusive intervals. Starts
+// of intervals are searched with any implementation: the last interval starting at or before the
+// point is the only one that may contain it. Both starts and ends are kept in the layout of the
+// implementation, so the end is read from the location of the start.
+
+use crate::binary_search::{DynamicBinarySearch, Layout};
+
+/// Why intervals were not accepted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalError {
+    /// Interval at index ends before it starts
+    Inverted(usize),
+    /// Interval at index does not start after the start of the previous one
+    Unsorted(usize),
+    /// Interval at index starts at or before the end of the previous one
+    Overlapping(usize),
+}
+
+impl std::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            IntervalError::Inverted(index) => write!(f, "interval {} ends before it starts", index),
+            IntervalError::Unsorted(index) => write!(f, "interval {} is not sorted", index),
+            IntervalError::Overlapping(index) => {
+                write!(f, "interval {} overlaps the previous one", index)
+            }
+        };
+    }
+}
+
+impl std::error::Error for IntervalError {}
+
+/// Result of a lookup
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Containment<I> {
+    /// Index of the interval containing the point
+    Inside(I),
+    /// Point is in no interval, but in the gap before the interval of index (number of intervals
+    /// when after the last one)
+    Gap(I),
+}
+
+/// Sorted, disjoint and inclusive intervals searched with implementation B
+pub struct IntervalTable<D, B> {
+    bs: B,
+    starts: Vec<D>,
+    ends: Vec<D>,
+}
+
+impl<D, B> IntervalTable<D, B>
+where
+    D: std::cmp::PartialOrd + std::marker::Copy,
+    B: Layout,
+{
+    /// Table searched with implementation bs, arranged in its layout
+    pub fn new(bs: B, intervals: &[(D, D)]) -> Result<Self, IntervalError> {
+        Self::validate(intervals)?;
+        let starts: Vec<D> = intervals
+            .iter()
+            .map(|interval: &(D, D)| interval.0)
+            .collect();
+        let ends: Vec<D> = intervals
+            .iter()
+            .map(|interval: &(D, D)| interval.1)
+            .collect();
+        return Ok(IntervalTable {
+            starts: bs.layout(&starts),
+            ends: bs.layout(&ends),
+            bs,
+        });
+    }
+
+    // Comparisons are written so that unordered values, like NaN, are rejected
+    fn validate(intervals: &[(D, D)]) -> Result<(), IntervalError> {
+        use std::cmp::Ordering;
+
+        let mut previous: Option<&(D, D)> = None;
+        for (index, interval) in intervals.iter().enumerate() {
+            if !matches!(
+                interval.0.partial_cmp(&interval.1),
+                Some(Ordering::Less | Ordering::Equal)
+            ) {
+                return Err(IntervalError::Inverted(index));
+            }
+            if let Some(before) = previous {
+                if before.0.partial_cmp(&interval.0) != Some(Ordering::Less) {
+                    return Err(IntervalError::Unsorted(index));
+                }
+                if before.1.partial_cmp(&interval.0) != Some(Ordering::Less) {
+                    return Err(IntervalError::Overlapping(index));
+                }
+            }
+            previous = Some(interval);
+        }
+        return Ok(());
+    }
+}
+
+impl<D, B> IntervalTable<D, B>
+where
+    D: std::cmp::PartialOrd + std::marker::Copy,
+{
+    /// Number of intervals
+    pub fn len(&self) -> usize {
+        return self.starts.len();
+    }
+
+    /// True, if there are no intervals
+    pub fn is_empty(&self) -> bool {
+        return self.starts.is_empty();
+    }
+
+    /// Interval containing point, or the gap it is in
+    pub fn lookup<I>(&self, point: &D) -> Containment<I>
+    where
+        I: num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: DynamicBinarySearch<D, I>,
+    {
+        use num_traits::cast::cast;
+
+        let after: I = self.bs.upper_bound(&self.starts, point);
+        if after == cast(0).unwrap() {
+            return Containment::Gap(after);
+        }
+        let candidate: I = after - cast(1).unwrap();
+        let location: I = self.bs.location(&self.starts, candidate);
+        if *point <= self.ends[cast::<I, usize>(location).unwrap()] {
+            return Containment::Inside(candidate);
+        }
+        return Containment::Gap(after);
+    }
+}
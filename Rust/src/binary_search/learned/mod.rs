@@ -0,0 +1,247 @@
+// Learned index: a piecewise-linear model predicts the position of a value in a sorted array of
+// integers, and an existing strategy finishes the search within the error window of prediction.
+// Segments are fitted greedily with a shrinking cone, like in FITing-tree: a segment starts at
+// a point, and is extended while some slope keeps every point within the requested error. The
+// chosen slope is then checked with rounding, and the segment ends before the first point off by
+// more than the error, so that the error is never exceeded. Distances from the first key of a
+// segment are taken in the integer type, as f64 does not tell apart keys beyond 2^53.
+// The model maps each key k to its lower bound, and k + 1 to its upper bound, so that values
+// between keys are predicted as well as keys. Prediction is monotone within a segment and clamped
+// to the positions of the segment, so the error measured at the fitted points bounds the error
+// of any value; results are the same as of the strategy over the whole array.
+
+use crate::binary_search::DynamicBinarySearch;
+
+/// Why array was not accepted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LearnedError {
+    /// Value at index is less than the previous one
+    Unsorted(usize),
+}
+
+impl std::fmt::Display for LearnedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            LearnedError::Unsorted(index) => write!(f, "value {} is not sorted", index),
+        };
+    }
+}
+
+impl std::error::Error for LearnedError {}
+
+// Line through the first point of segment, whose key is in keys
+#[derive(Clone, Copy)]
+struct Segment {
+    slope: f64,
+    start: usize,
+}
+
+/// Piecewise-linear model of a sorted array of integers, with a strategy for the last mile
+pub struct LearnedIndex<'a, D, B> {
+    data: &'a [D],
+    bs: B,
+    keys: Vec<D>,
+    segments: Vec<Segment>,
+    max_error: usize,
+    build_time: std::time::Duration,
+}
+
+impl<'a, D, B> LearnedIndex<'a, D, B>
+where
+    D: num::PrimInt,
+    B: DynamicBinarySearch<D, usize>,
+{
+    /// Model for values in ascending order, whose error is max_error positions at most; bs
+    /// searches the segments and the error window of a prediction
+    pub fn new(data: &'a [D], bs: B, max_error: usize) -> Result<Self, LearnedError> {
+        let started: std::time::Instant = std::time::Instant::now();
+        for index in 1..data.len() {
+            if data[index] < data[index - 1] {
+                return Err(LearnedError::Unsorted(index));
+            }
+        }
+
+        let mut index: LearnedIndex<D, B> = LearnedIndex {
+            data,
+            bs,
+            keys: Vec::new(),
+            segments: Vec::new(),
+            max_error: 0,
+            build_time: std::time::Duration::ZERO,
+        };
+        let points: Vec<(D, usize)> = Self::points(data);
+        index.fit(&points, max_error);
+        // Error of the model as evaluated, i.e. also with rounding
+        let mut segment: usize = 0;
+        for (key, position) in points.iter() {
+            while (segment + 1 < index.keys.len()) && (index.keys[segment + 1] <= *key) {
+                segment += 1;
+            }
+            let predicted: usize = index.predict(segment, key);
+            index.max_error = index.max_error.max(predicted.abs_diff(*position));
+        }
+        index.build_time = started.elapsed();
+        return Ok(index);
+    }
+
+    // Each key with its lower bound, and key + 1 with its upper bound
+    fn points(data: &[D]) -> Vec<(D, usize)> {
+        let mut points: Vec<(D, usize)> = Vec::new();
+        let mut position: usize = 0;
+        while position < data.len() {
+            let key: D = data[position];
+            points.push((key, position));
+            while (position < data.len()) && (data[position] == key) {
+                position += 1;
+            }
+            // Unless key + 1 is the next key, or overflows
+            let next: Option<D> = key.checked_add(&D::one());
+            if let Some(next) = next.filter(|next: &D| data.get(position) != Some(next)) {
+                points.push((next, position));
+            }
+        }
+        return points;
+    }
+
+    // Shrinking cone: the range of slopes keeping all points of segment within error
+    fn fit(&mut self, points: &[(D, usize)], error: usize) {
+        let mut first: usize = 0;
+        while first < points.len() {
+            let (origin, start): (D, usize) = points[first];
+            let mut low: f64 = 0.0;
+            let mut high: f64 = f64::INFINITY;
+            let mut last: usize = first + 1;
+            while last < points.len() {
+                let dx: f64 = Self::distance(&origin, &points[last].0);
+                let dy: f64 = (points[last].1 - start) as f64;
+                let point_low: f64 = low.max((dy - error as f64) / dx);
+                let point_high: f64 = high.min((dy + error as f64) / dx);
+                if point_low > point_high {
+                    break;
+                }
+                low = point_low;
+                high = point_high;
+                last += 1;
+            }
+            let model: Segment = Segment {
+                slope: if high.is_finite() {
+                    (low + high) / 2.0
+                } else {
+                    low
+                },
+                start,
+            };
+            // The first point is predicted exactly, so each segment keeps at least it. Clamping to
+            // the start of the next segment only moves predictions towards the positions
+            last = (first + 1..last)
+                .find(|point: &usize| {
+                    let (key, position): (D, usize) = points[*point];
+                    return Self::offset(&model, &origin, &key).abs_diff(position) > error;
+                })
+                .unwrap_or(last);
+            self.keys.push(origin);
+            self.segments.push(model);
+            first = last;
+        }
+    }
+
+    // Distance from origin to value not less than it, exact while it fits into D
+    #[inline]
+    fn distance(origin: &D, value: &D) -> f64 {
+        return match value.checked_sub(origin) {
+            Some(distance) => distance.to_f64().unwrap(),
+            None => value.to_f64().unwrap() - origin.to_f64().unwrap(),
+        };
+    }
+
+    // Position on the line of segment, which is monotone to value
+    #[inline]
+    fn offset(model: &Segment, origin: &D, value: &D) -> usize {
+        let offset: f64 = Self::distance(origin, value) * model.slope;
+        return model.start.saturating_add(offset.round() as usize);
+    }
+
+    // Position predicted by segment, which is monotone to value, and within the segment
+    #[inline]
+    fn predict(&self, segment: usize, value: &D) -> usize {
+        let end: usize = self
+            .segments
+            .get(segment + 1)
+            .map_or(self.data.len(), |next: &Segment| next.start);
+        return Self::offset(&self.segments[segment], &self.keys[segment], value).min(end);
+    }
+
+    /// Number of linear segments
+    pub fn segments(&self) -> usize {
+        return self.segments.len();
+    }
+
+    /// Size of the model in bytes
+    pub fn model_size(&self) -> usize {
+        return self.keys.len() * std::mem::size_of::<D>()
+            + self.segments.len() * std::mem::size_of::<Segment>();
+    }
+
+    /// The largest distance of a prediction from the position searched for
+    pub fn max_error(&self) -> usize {
+        return self.max_error;
+    }
+
+    /// Time spent to validate the array and to fit the model
+    pub fn build_time(&self) -> std::time::Duration {
+        return self.build_time;
+    }
+
+    /// Position of the first element that is not less than value
+    #[inline]
+    pub fn lower_bound<I>(&self, value: &D) -> I
+    where
+        I: num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        // Predictions are not needed before the first segment
+        let segment: usize = self
+            .bs
+            .partition_point(&self.keys, |key: &D| *key <= *value);
+        if segment == 0 {
+            return cast(0).unwrap();
+        }
+        let predicted: usize = self.predict(segment - 1, value);
+        let first: usize = predicted.saturating_sub(self.max_error);
+        let last: usize = predicted
+            .saturating_add(self.max_error)
+            .min(self.data.len());
+        let position: usize = self.bs.lower_bound(&self.data[first..last], value);
+        return cast(first + position).unwrap();
+    }
+
+    /// Position of the first element that is greater than value
+    #[inline]
+    pub fn upper_bound<I>(&self, value: &D) -> I
+    where
+        I: num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        return match value.checked_add(&D::one()) {
+            Some(next) => self.lower_bound(&next),
+            None => cast(self.data.len()).unwrap(),
+        };
+    }
+
+    /// Location of an element equal to value, or position where value could be inserted
+    #[inline]
+    pub fn search<I>(&self, value: &D) -> Result<I, I>
+    where
+        I: num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = self.lower_bound(value);
+        if (position < self.data.len()) && (self.data[position] == *value) {
+            return Ok(cast(position).unwrap());
+        }
+        return Err(cast(position).unwrap());
+    }
+}
@@ -1,21 +1,672 @@
+// Bound queries (lower_bound, upper_bound, equal_range and partition_point) and insertion points
+// of search report positions in sorted order, i.e. the number of elements ordered before the
+// position. For monotonic arrays this is the index into the array, for Eytzinger and S-tree
+// layouts it is the rank of the element. Locations of found elements are always indices into the
+// searched array.
+
+/// Which one of equal elements is located
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Duplicates {
+    /// The first one in sorted order
+    Leftmost,
+    /// The last one in sorted order
+    Rightmost,
+    /// Whichever the implementation finds fastest, like r#impl does
+    Any,
+}
+
+/// Which one of two equally close elements is the nearest one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The smaller one, i.e. floor
+    Floor,
+    /// The greater one, i.e. ceiling
+    Ceiling,
+}
+
+/// Distance between values, for finding the nearest one
+pub trait Distance: std::marker::Sized {
+    /// Distance from lesser to self, none if it does not fit into the type
+    fn distance(&self, lesser: &Self) -> Option<Self>;
+}
+
+// Integers may overflow, floats go to infinity instead
+macro_rules! integer_distance {
+    ($($type:ty),*) => {
+        $(
+            impl Distance for $type {
+                #[inline]
+                fn distance(&self, lesser: &Self) -> Option<Self> {
+                    return self.checked_sub(*lesser);
+                }
+            }
+        )*
+    };
+}
+
+integer_distance!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Distance for f32 {
+    #[inline]
+    fn distance(&self, lesser: &Self) -> Option<Self> {
+        return Some(self - lesser);
+    }
+}
+
+impl Distance for f64 {
+    #[inline]
+    fn distance(&self, lesser: &Self) -> Option<Self> {
+        return Some(self - lesser);
+    }
+}
+
+// Whether value is closer to below than to above, where below <= value <= above. A distance that
+// overflows is the larger one; both cannot overflow, as their sum is at most above - below.
+#[inline]
+fn closer_to_floor<D>(below: &D, value: &D, above: &D, tie: TieBreak) -> bool
+where
+    D: std::cmp::PartialOrd + Distance,
+{
+    return match (value.distance(below), above.distance(value)) {
+        (Some(below_distance), Some(above_distance)) => {
+            (below_distance < above_distance)
+                || ((below_distance == above_distance) && (tie == TieBreak::Floor))
+        }
+        (None, _) => false,
+        (Some(_), None) => true,
+    };
+}
+
+// Location of the element at position, none at the end of data
+#[inline]
+pub(crate) fn located_at<I, L>(length: usize, position: I, location: L) -> Option<I>
+where
+    I: num_traits::NumCast + std::marker::Copy,
+    L: FnOnce(I) -> I,
+{
+    use num_traits::cast::cast;
+
+    return if cast::<I, usize>(position).unwrap() < length {
+        Some(location(position))
+    } else {
+        None
+    };
+}
+
+// Location of the element before position, none at the start of data
+#[inline]
+pub(crate) fn located_before<I, L>(position: I, location: L) -> Option<I>
+where
+    I: num::Integer + std::marker::Copy,
+    L: FnOnce(I) -> I,
+{
+    return if position > I::zero() {
+        Some(location(position - I::one()))
+    } else {
+        None
+    };
+}
+
+// The one of floor and ceiling that is closer to value, given their locations
+#[inline]
+pub(crate) fn closest<D, I>(
+    data: &[D],
+    value: &D,
+    tie: TieBreak,
+    floor: Option<I>,
+    ceiling: Option<I>,
+) -> Option<I>
+where
+    D: std::cmp::PartialOrd + Distance,
+    I: num_traits::NumCast + std::marker::Copy,
+{
+    use num_traits::cast::cast;
+
+    return match (floor, ceiling) {
+        (Some(below), Some(above)) => {
+            let below_value: &D = &data[cast::<I, usize>(below).unwrap()];
+            let above_value: &D = &data[cast::<I, usize>(above).unwrap()];
+            if closer_to_floor(below_value, value, above_value, tie) {
+                Some(below)
+            } else {
+                Some(above)
+            }
+        }
+        (below, None) => below,
+        (None, above) => above,
+    };
+}
+
+// Comparator turned to a partition predicate, which records the ordering of the last element not
+// ordered before the target. Each implementation probes such elements right to left, so after the
+// partition it is the ordering of the element at the found position and no extra comparison is
+// needed.
+pub(crate) struct ThreeWay<F> {
+    f: F,
+    last: std::cmp::Ordering,
+}
+
+impl<F> ThreeWay<F> {
+    #[inline]
+    pub(crate) fn new(f: F) -> Self {
+        return ThreeWay {
+            f,
+            last: std::cmp::Ordering::Less,
+        };
+    }
+
+    #[inline]
+    pub(crate) fn less<D>(&mut self, element: &D) -> bool
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+    {
+        use std::cmp::Ordering;
+
+        let ordering: Ordering = (self.f)(element);
+        if ordering != Ordering::Less {
+            self.last = ordering;
+        }
+        return ordering == Ordering::Less;
+    }
+
+    #[inline]
+    pub(crate) fn found(&self) -> bool {
+        return self.last == std::cmp::Ordering::Equal;
+    }
+}
+
+/// Arrangement of the searched array an implementation expects, for structures that build their
+/// arrays from sorted data
+pub trait Layout {
+    /// Elements of sorted monotonic array in the order searched by the implementation
+    fn layout<D: std::clone::Clone>(&self, monotonic: &[D]) -> Vec<D> {
+        return monotonic.to_vec();
+    }
+}
+
 pub trait DynamicBinarySearch<D, I>
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer,
 {
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I>;
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Position of the first element that is not less than value
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Position of the first element that is greater than value
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Location of an element equal to value, or position where value could be inserted
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Position of the first element for which the monotone predicate returns false
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized;
+
+    /// Like search, but the comparator tells the ordering of an element relative to the target
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized;
+
+    /// Like search, but elements are compared by the key extracted from them
+    fn search_by_key<K, F>(&self, data: &[D], key: &K, mut f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> K,
+        K: std::cmp::Ord,
+        Self: Sized,
+    {
+        return self.search_by(data, |element: &D| f(element).cmp(key));
+    }
+
+    /// Like search, but with one Ord::cmp per probe, for keys that are expensive to compare
+    fn search_cmp(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::Ord,
+        Self: Sized,
+    {
+        return self.search_by(data, |element: &D| element.cmp(value));
+    }
+
+    /// Position of the first element that the comparator does not order before the target
+    fn lower_bound_by<F>(&self, data: &[D], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use std::cmp::Ordering;
+
+        return self.partition_point(data, |element: &D| f(element) == Ordering::Less);
+    }
+
+    /// Position of the first element that the comparator orders after the target
+    fn upper_bound_by<F>(&self, data: &[D], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use std::cmp::Ordering;
+
+        return self.partition_point(data, |element: &D| f(element) != Ordering::Greater);
+    }
+
+    /// Like search, but value may be any borrowed form of the elements, e.g. str for String
+    fn search_borrowed<Q>(&self, data: &[D], value: &Q) -> Result<I, I>
+    where
+        D: std::borrow::Borrow<Q>,
+        Q: std::cmp::Ord + ?Sized,
+        Self: Sized,
+    {
+        return self.search_by(data, |element: &D| element.borrow().cmp(value));
+    }
+
+    /// Like lower_bound, but value may be any borrowed form of the elements
+    fn lower_bound_borrowed<Q>(&self, data: &[D], value: &Q) -> I
+    where
+        D: std::borrow::Borrow<Q>,
+        Q: std::cmp::Ord + ?Sized,
+        Self: Sized,
+    {
+        return self.lower_bound_by(data, |element: &D| element.borrow().cmp(value));
+    }
+
+    /// Like upper_bound, but value may be any borrowed form of the elements
+    fn upper_bound_borrowed<Q>(&self, data: &[D], value: &Q) -> I
+    where
+        D: std::borrow::Borrow<Q>,
+        Q: std::cmp::Ord + ?Sized,
+        Self: Sized,
+    {
+        return self.upper_bound_by(data, |element: &D| element.borrow().cmp(value));
+    }
+
+    /// Half-open range of positions of elements equal to value
+    fn equal_range(&self, data: &[D], value: &D) -> std::ops::Range<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self.lower_bound(data, value)..self.upper_bound(data, value);
+    }
+
+    /// Number of elements equal to value
+    fn count(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        let range: std::ops::Range<I> = self.equal_range(data, value);
+        return range.end - range.start;
+    }
+
+    /// Half-open range of positions of elements within given range of values
+    fn range_indices<R>(&self, data: &[D], range: R) -> std::ops::Range<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+        R: std::ops::RangeBounds<D>,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+        use std::ops::Bound;
+
+        let start: I = match range.start_bound() {
+            Bound::Included(low) => self.lower_bound(data, low),
+            Bound::Excluded(low) => self.upper_bound(data, low),
+            Bound::Unbounded => cast(0).unwrap(),
+        };
+        let end: I = match range.end_bound() {
+            Bound::Included(high) => self.upper_bound(data, high),
+            Bound::Excluded(high) => self.lower_bound(data, high),
+            Bound::Unbounded => cast(data.len()).unwrap(),
+        };
+        // Range of values may be empty, e.g. 3..1
+        return if start < end {
+            start..end
+        } else {
+            start..start
+        };
+    }
+
+    /// Number of elements within given range of values
+    fn count_in_range<R>(&self, data: &[D], range: R) -> I
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+        R: std::ops::RangeBounds<D>,
+        Self: Sized,
+    {
+        let indices: std::ops::Range<I> = self.range_indices(data, range);
+        return indices.end - indices.start;
+    }
+
+    /// Location in data of the element at given position
+    fn location(&self, _data: &[D], position: I) -> I {
+        return position;
+    }
+
+    /// Location of an element equal to value, chosen by the duplicate policy
+    fn find(&self, data: &[D], value: &D, policy: Duplicates) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        use num_traits::cast::cast;
+
+        let position: I = match policy {
+            Duplicates::Any => return self.r#impl(data, value),
+            Duplicates::Leftmost => self.lower_bound(data, value),
+            Duplicates::Rightmost => {
+                let upper: I = self.upper_bound(data, value);
+                if upper == cast(0).unwrap() {
+                    return None;
+                }
+                upper - cast(1).unwrap()
+            }
+        };
+        let index: usize = cast(position).unwrap();
+        if index < data.len() {
+            let location: I = self.location(data, position);
+            if data[cast::<I, usize>(location).unwrap()] == *value {
+                return Some(location);
+            }
+        }
+        return None;
+    }
+
+    /// Location of the greatest element not greater than value, none if all are greater
+    fn floor(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let upper: I = self.upper_bound(data, value);
+        return located_before(upper, |position: I| self.location(data, position));
+    }
+
+    /// Location of the least element not less than value, none if all are less
+    fn ceiling(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        return located_at(data.len(), lower, |position: I| {
+            self.location(data, position)
+        });
+    }
+
+    /// Location of the element closest to value, none only if data is empty
+    fn nearest(&self, data: &[D], value: &D, tie: TieBreak) -> Option<I>
+    where
+        D: std::cmp::PartialOrd + Distance + std::marker::Copy,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        let floor: Option<I> = located_before(lower, |position: I| self.location(data, position));
+        let ceiling: Option<I> = located_at(data.len(), lower, |position: I| {
+            self.location(data, position)
+        });
+        return closest(data, value, tie, floor, ceiling);
+    }
 }
 
 pub trait StaticBinarySearch<D, I, const N: usize>
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer,
 {
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>;
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Position of the first element that is not less than value
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Position of the first element that is greater than value
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Location of an element equal to value, or position where value could be inserted
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd;
+
+    /// Position of the first element for which the monotone predicate returns false
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized;
+
+    /// Like search, but the comparator tells the ordering of an element relative to the target
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized;
+
+    /// Like search, but elements are compared by the key extracted from them
+    fn search_by_key<K, F>(&self, data: &[D; N], key: &K, mut f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> K,
+        K: std::cmp::Ord,
+        Self: Sized,
+    {
+        return self.search_by(data, |element: &D| f(element).cmp(key));
+    }
+
+    /// Like search, but with one Ord::cmp per probe, for keys that are expensive to compare
+    fn search_cmp(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::Ord,
+        Self: Sized,
+    {
+        return self.search_by(data, |element: &D| element.cmp(value));
+    }
+
+    /// Position of the first element that the comparator does not order before the target
+    fn lower_bound_by<F>(&self, data: &[D; N], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use std::cmp::Ordering;
+
+        return self.partition_point(data, |element: &D| f(element) == Ordering::Less);
+    }
+
+    /// Position of the first element that the comparator orders after the target
+    fn upper_bound_by<F>(&self, data: &[D; N], mut f: F) -> I
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use std::cmp::Ordering;
+
+        return self.partition_point(data, |element: &D| f(element) != Ordering::Greater);
+    }
+
+    /// Like search, but value may be any borrowed form of the elements, e.g. str for String
+    fn search_borrowed<Q>(&self, data: &[D; N], value: &Q) -> Result<I, I>
+    where
+        D: std::borrow::Borrow<Q>,
+        Q: std::cmp::Ord + ?Sized,
+        Self: Sized,
+    {
+        return self.search_by(data, |element: &D| element.borrow().cmp(value));
+    }
+
+    /// Like lower_bound, but value may be any borrowed form of the elements
+    fn lower_bound_borrowed<Q>(&self, data: &[D; N], value: &Q) -> I
+    where
+        D: std::borrow::Borrow<Q>,
+        Q: std::cmp::Ord + ?Sized,
+        Self: Sized,
+    {
+        return self.lower_bound_by(data, |element: &D| element.borrow().cmp(value));
+    }
+
+    /// Like upper_bound, but value may be any borrowed form of the elements
+    fn upper_bound_borrowed<Q>(&self, data: &[D; N], value: &Q) -> I
+    where
+        D: std::borrow::Borrow<Q>,
+        Q: std::cmp::Ord + ?Sized,
+        Self: Sized,
+    {
+        return self.upper_bound_by(data, |element: &D| element.borrow().cmp(value));
+    }
+
+    /// Half-open range of positions of elements equal to value
+    fn equal_range(&self, data: &[D; N], value: &D) -> std::ops::Range<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return self.lower_bound(data, value)..self.upper_bound(data, value);
+    }
+
+    /// Number of elements equal to value
+    fn count(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        let range: std::ops::Range<I> = self.equal_range(data, value);
+        return range.end - range.start;
+    }
+
+    /// Half-open range of positions of elements within given range of values
+    fn range_indices<R>(&self, data: &[D; N], range: R) -> std::ops::Range<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+        R: std::ops::RangeBounds<D>,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+        use std::ops::Bound;
+
+        let start: I = match range.start_bound() {
+            Bound::Included(low) => self.lower_bound(data, low),
+            Bound::Excluded(low) => self.upper_bound(data, low),
+            Bound::Unbounded => cast(0).unwrap(),
+        };
+        let end: I = match range.end_bound() {
+            Bound::Included(high) => self.upper_bound(data, high),
+            Bound::Excluded(high) => self.lower_bound(data, high),
+            Bound::Unbounded => cast(N).unwrap(),
+        };
+        // Range of values may be empty, e.g. 3..1
+        return if start < end {
+            start..end
+        } else {
+            start..start
+        };
+    }
+
+    /// Number of elements within given range of values
+    fn count_in_range<R>(&self, data: &[D; N], range: R) -> I
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+        R: std::ops::RangeBounds<D>,
+        Self: Sized,
+    {
+        let indices: std::ops::Range<I> = self.range_indices(data, range);
+        return indices.end - indices.start;
+    }
+
+    /// Location in data of the element at given position
+    fn location(&self, _data: &[D; N], position: I) -> I {
+        return position;
+    }
+
+    /// Location of an element equal to value, chosen by the duplicate policy
+    fn find(&self, data: &[D; N], value: &D, policy: Duplicates) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        use num_traits::cast::cast;
+
+        let position: I = match policy {
+            Duplicates::Any => return self.r#impl(data, value),
+            Duplicates::Leftmost => self.lower_bound(data, value),
+            Duplicates::Rightmost => {
+                let upper: I = self.upper_bound(data, value);
+                if upper == cast(0).unwrap() {
+                    return None;
+                }
+                upper - cast(1).unwrap()
+            }
+        };
+        let index: usize = cast(position).unwrap();
+        if index < N {
+            let location: I = self.location(data, position);
+            if data[cast::<I, usize>(location).unwrap()] == *value {
+                return Some(location);
+            }
+        }
+        return None;
+    }
+
+    /// Location of the greatest element not greater than value, none if all are greater
+    fn floor(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let upper: I = self.upper_bound(data, value);
+        return located_before(upper, |position: I| self.location(data, position));
+    }
+
+    /// Location of the least element not less than value, none if all are less
+    fn ceiling(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        return located_at(N, lower, |position: I| self.location(data, position));
+    }
+
+    /// Location of the element closest to value, none only if data is empty
+    fn nearest(&self, data: &[D; N], value: &D, tie: TieBreak) -> Option<I>
+    where
+        D: std::cmp::PartialOrd + Distance + std::marker::Copy,
+        I: num_traits::NumCast + std::marker::Copy,
+    {
+        let lower: I = self.lower_bound(data, value);
+        let floor: Option<I> = located_before(lower, |position: I| self.location(data, position));
+        let ceiling: Option<I> = located_at(N, lower, |position: I| self.location(data, position));
+        return closest(data, value, tie, floor, ceiling);
+    }
 }
 
 pub mod alternative;
+pub mod batch;
+pub mod bisection;
+pub mod descending;
+pub mod direct;
+pub mod exponential;
 pub mod eytzinger;
+pub mod float;
+pub mod interpolation;
+pub mod interval;
+pub mod learned;
 pub mod power;
+pub mod prefix;
 pub mod range;
+pub mod simd;
+pub mod stree;
 pub mod traditional;
+pub mod uniform;
+pub mod veb;
+pub mod weighted;
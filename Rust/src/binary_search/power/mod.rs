@@ -24,24 +24,58 @@ pub mod utility {
     }
 }
 
-use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
+
+// Positions go up to the length of data, so unlike r#impl, which accepts one more element than
+// the index type can count, partitions need the length to fit the index type
+#[inline]
+fn assert_length_fits<I>(length: usize)
+where
+    I: num_traits::NumCast,
+{
+    use num_traits::cast::cast;
+
+    assert!(
+        cast::<usize, I>(length).is_some(),
+        "length {} does not fit the index type",
+        length
+    );
+}
 
 trait DynamicPower<D, I>
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned + num::Integer,
 {
-    fn without_bound_check(data: &[D], value: &D) -> Option<I>;
-    fn with_bound_check(data: &[D], value: &D) -> Option<I>;
+    fn without_bound_check(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn with_bound_check(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn partition_without_bound_check<F>(data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
+    fn partition_with_bound_check<F>(data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
 }
 
 trait StaticPower<D, I, const N: usize>
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned + num::Integer,
 {
-    fn without_bound_check(data: &[D; N], value: &D) -> Option<I>;
-    fn with_bound_check(data: &[D; N], value: &D) -> Option<I>;
+    fn without_bound_check(data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn with_bound_check(data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn partition_without_bound_check<F>(data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
+    fn partition_with_bound_check<F>(data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
 }
 
 pub struct DynamicImplementation;
@@ -50,7 +84,6 @@ pub struct StaticImplementation;
 
 impl<D, I> DynamicPower<D, I> for DynamicImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -59,7 +92,10 @@ where
         + std::ops::ShrAssign<u16>,
 {
     #[inline]
-    fn without_bound_check(data: &[D], value: &D) -> Option<I> {
+    fn without_bound_check(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let half_power: usize = self::utility::previous_power_of_two(data.len());
@@ -81,7 +117,10 @@ where
     }
 
     #[inline]
-    fn with_bound_check(data: &[D], value: &D) -> Option<I> {
+    fn with_bound_check(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let half_power: usize = self::utility::previous_power_of_two(data.len());
@@ -101,11 +140,62 @@ where
         }
         return None;
     }
+
+    #[inline]
+    fn partition_without_bound_check<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        assert_length_fits::<I>(data.len());
+        let half_power: usize = self::utility::previous_power_of_two(data.len());
+        let mut low: I = cast(0).unwrap();
+        let mut width: I = cast(half_power).unwrap();
+        while width > cast(0).unwrap() {
+            let mid: I = low | width;
+            let index: usize = cast(mid).unwrap();
+            if pred(&data[index]) {
+                low = mid;
+            }
+            width >>= 1u16;
+        }
+        let index: usize = cast(low).unwrap();
+        if pred(&data[index]) {
+            return low + cast(1).unwrap();
+        }
+        return low;
+    }
+
+    #[inline]
+    fn partition_with_bound_check<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        assert_length_fits::<I>(data.len());
+        let half_power: usize = self::utility::previous_power_of_two(data.len());
+        let mut low: I = cast(0).unwrap();
+        let mut width: I = cast(half_power).unwrap();
+        while width > cast(0).unwrap() {
+            let mid: I = low | width;
+            let index: usize = cast(mid).unwrap();
+            if (index < data.len()) && pred(&data[index]) {
+                low = mid;
+            }
+            width >>= 1u16;
+        }
+        let index: usize = cast(low).unwrap();
+        if pred(&data[index]) {
+            return low + cast(1).unwrap();
+        }
+        return low;
+    }
 }
 
 impl<D, I, const N: usize> StaticPower<D, I, N> for StaticImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -114,7 +204,10 @@ where
         + std::ops::ShrAssign<u16>,
 {
     #[inline]
-    fn without_bound_check(data: &[D; N], value: &D) -> Option<I> {
+    fn without_bound_check(data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use self::utility::{log2, previous_power_of_two};
         use num_traits::cast::cast;
 
@@ -144,7 +237,10 @@ where
     }
 
     #[inline]
-    fn with_bound_check(data: &[D; N], value: &D) -> Option<I> {
+    fn with_bound_check(data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use self::utility::{log2, previous_power_of_two};
         use num_traits::cast::cast;
 
@@ -172,11 +268,76 @@ where
         }
         return None;
     }
+
+    #[inline]
+    fn partition_without_bound_check<F>(data: &[D; N], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use self::utility::{log2, previous_power_of_two};
+        use num_traits::cast::cast;
+
+        assert_length_fits::<I>(N);
+        let half_power: usize = previous_power_of_two(N);
+        let p: u32 = if half_power > 0 {
+            1 + log2(half_power).unwrap()
+        } else {
+            cast(0).unwrap()
+        };
+        let mut low: I = cast(0).unwrap();
+        let mut width: I = cast(half_power).unwrap();
+        for _i in 1..=p {
+            let mid: I = low | width;
+            let index: usize = cast(mid).unwrap();
+            if pred(&data[index]) {
+                low = mid;
+            }
+            width >>= 1u16;
+        }
+        let index: usize = cast(low).unwrap();
+        if pred(&data[index]) {
+            return low + cast(1).unwrap();
+        }
+        return low;
+    }
+
+    #[inline]
+    fn partition_with_bound_check<F>(data: &[D; N], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use self::utility::{log2, previous_power_of_two};
+        use num_traits::cast::cast;
+
+        assert_length_fits::<I>(N);
+        let half_power: usize = previous_power_of_two(N);
+        let p: u32 = if half_power > 0 {
+            1 + log2(half_power).unwrap()
+        } else {
+            cast(0).unwrap()
+        };
+        let mut low: I = cast(0).unwrap();
+        let mut width: I = cast(half_power).unwrap();
+        for _i in 1..=p {
+            let mid: I = low | width;
+            let index: usize = cast(mid).unwrap();
+            if (index < data.len()) && pred(&data[index]) {
+                low = mid;
+            }
+            width >>= 1u16;
+        }
+        let index: usize = cast(low).unwrap();
+        if pred(&data[index]) {
+            return low + cast(1).unwrap();
+        }
+        return low;
+    }
 }
 
+impl Layout for DynamicImplementation {}
+
 impl<D, I> DynamicBinarySearch<D, I> for DynamicImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -185,7 +346,10 @@ where
         + std::ops::ShrAssign<u16>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         if data.len() > 0 {
             if self::utility::is_power_of_two_or_zero(data.len()) {
                 return Self::without_bound_check(data, value);
@@ -195,11 +359,118 @@ where
         }
         return None;
     }
+
+    /// Position of the first element that is not less than value; panics if length of data does
+    /// not fit I
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            if self::utility::is_power_of_two_or_zero(data.len()) {
+                return Self::partition_without_bound_check(data, |element: &D| *element < *value);
+            } else {
+                return Self::partition_with_bound_check(data, |element: &D| *element < *value);
+            }
+        }
+        return cast(0).unwrap();
+    }
+
+    /// Position of the first element that is greater than value; panics if length of data does
+    /// not fit I
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            if self::utility::is_power_of_two_or_zero(data.len()) {
+                return Self::partition_without_bound_check(data, |element: &D| *element <= *value);
+            } else {
+                return Self::partition_with_bound_check(data, |element: &D| *element <= *value);
+            }
+        }
+        return cast(0).unwrap();
+    }
+
+    /// Location of an element equal to value, or position where value could be inserted; panics
+    /// if length of data does not fit I
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let position: I = if self::utility::is_power_of_two_or_zero(data.len()) {
+                Self::partition_without_bound_check(data, |element: &D| *element < *value)
+            } else {
+                Self::partition_with_bound_check(data, |element: &D| *element < *value)
+            };
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    /// Position of the first element for which predicate returns false; panics if length of data
+    /// does not fit I
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            if self::utility::is_power_of_two_or_zero(data.len()) {
+                return Self::partition_without_bound_check(data, pred);
+            } else {
+                return Self::partition_with_bound_check(data, pred);
+            }
+        }
+        return cast(0).unwrap();
+    }
+
+    /// Like search, but with a comparator; panics if length of data does not fit I
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = if self::utility::is_power_of_two_or_zero(data.len()) {
+                Self::partition_without_bound_check(data, |element: &D| three_way.less(element))
+            } else {
+                Self::partition_with_bound_check(data, |element: &D| three_way.less(element))
+            };
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for StaticImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -208,7 +479,10 @@ where
         + std::ops::ShrAssign<u16>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         if N > 0 {
             if self::utility::is_power_of_two_or_zero(N) {
                 return Self::without_bound_check(data, value);
@@ -218,4 +492,112 @@ where
         }
         return None;
     }
+
+    /// Position of the first element that is not less than value; panics if length of data does
+    /// not fit I
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            if self::utility::is_power_of_two_or_zero(N) {
+                return Self::partition_without_bound_check(data, |element: &D| *element < *value);
+            } else {
+                return Self::partition_with_bound_check(data, |element: &D| *element < *value);
+            }
+        }
+        return cast(0).unwrap();
+    }
+
+    /// Position of the first element that is greater than value; panics if length of data does
+    /// not fit I
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            if self::utility::is_power_of_two_or_zero(N) {
+                return Self::partition_without_bound_check(data, |element: &D| *element <= *value);
+            } else {
+                return Self::partition_with_bound_check(data, |element: &D| *element <= *value);
+            }
+        }
+        return cast(0).unwrap();
+    }
+
+    /// Location of an element equal to value, or position where value could be inserted; panics
+    /// if length of data does not fit I
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let position: I = if self::utility::is_power_of_two_or_zero(N) {
+                Self::partition_without_bound_check(data, |element: &D| *element < *value)
+            } else {
+                Self::partition_with_bound_check(data, |element: &D| *element < *value)
+            };
+            let index: usize = cast(position).unwrap();
+            if (index < N) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    /// Position of the first element for which predicate returns false; panics if length of data
+    /// does not fit I
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            if self::utility::is_power_of_two_or_zero(N) {
+                return Self::partition_without_bound_check(data, pred);
+            } else {
+                return Self::partition_with_bound_check(data, pred);
+            }
+        }
+        return cast(0).unwrap();
+    }
+
+    /// Like search, but with a comparator; panics if length of data does not fit I
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = if self::utility::is_power_of_two_or_zero(N) {
+                Self::partition_without_bound_check(data, |element: &D| three_way.less(element))
+            } else {
+                Self::partition_with_bound_check(data, |element: &D| three_way.less(element))
+            };
+            let index: usize = cast(position).unwrap();
+            if (index < N) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
@@ -0,0 +1,225 @@
+// Prefix searches over sorted strings and byte strings, i.e. elements that are AsRef<[u8]>.
+// Elements starting with a prefix are consecutive in sorted order, so they are found with two
+// bound queries. The comparator compares only the first bytes of elements, as many as there are
+// in the prefix.
+
+use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+
+// Ordering of element relative to the elements starting with prefix
+#[inline]
+fn prefix_cmp(element: &[u8], prefix: &[u8]) -> std::cmp::Ordering {
+    let head: &[u8] = &element[..std::cmp::min(element.len(), prefix.len())];
+    return head.cmp(prefix);
+}
+
+pub trait DynamicPrefixSearch<D, I>: DynamicBinarySearch<D, I>
+where
+    D: AsRef<[u8]>,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    /// Half-open range of positions of elements starting with prefix
+    fn prefix_range<P>(&self, data: &[D], prefix: &P) -> std::ops::Range<I>
+    where
+        P: AsRef<[u8]> + ?Sized,
+        Self: Sized,
+    {
+        let prefix: &[u8] = prefix.as_ref();
+        let start: I =
+            self.lower_bound_by(data, |element: &D| prefix_cmp(element.as_ref(), prefix));
+        let end: I = self.upper_bound_by(data, |element: &D| prefix_cmp(element.as_ref(), prefix));
+        return start..end;
+    }
+
+    /// Elements starting with prefix, in sorted order
+    fn prefix_iter<'a, P>(&'a self, data: &'a [D], prefix: &P) -> DynamicPrefixIter<'a, D, I, Self>
+    where
+        P: AsRef<[u8]> + ?Sized,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        let range: std::ops::Range<I> = self.prefix_range(data, prefix);
+        return DynamicPrefixIter {
+            bs: self,
+            data,
+            positions: cast(range.start).unwrap()..cast(range.end).unwrap(),
+            index_type: std::marker::PhantomData,
+        };
+    }
+}
+
+impl<D, I, B> DynamicPrefixSearch<D, I> for B
+where
+    D: AsRef<[u8]>,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+}
+
+/// Iterator over elements of positions in range, located by the implementation
+pub struct DynamicPrefixIter<'a, D, I, B> {
+    bs: &'a B,
+    data: &'a [D],
+    positions: std::ops::Range<usize>,
+    index_type: std::marker::PhantomData<I>,
+}
+
+impl<'a, D, I, B> DynamicPrefixIter<'a, D, I, B>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    #[inline]
+    fn element(&self, position: usize) -> &'a D {
+        use num_traits::cast::cast;
+
+        let location: I = self.bs.location(self.data, cast(position).unwrap());
+        return &self.data[cast::<I, usize>(location).unwrap()];
+    }
+}
+
+impl<'a, D, I, B> Iterator for DynamicPrefixIter<'a, D, I, B>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    type Item = &'a D;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a D> {
+        let position: usize = self.positions.next()?;
+        return Some(self.element(position));
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.positions.size_hint();
+    }
+}
+
+impl<'a, D, I, B> DoubleEndedIterator for DynamicPrefixIter<'a, D, I, B>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a D> {
+        let position: usize = self.positions.next_back()?;
+        return Some(self.element(position));
+    }
+}
+
+impl<'a, D, I, B> ExactSizeIterator for DynamicPrefixIter<'a, D, I, B>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+}
+
+pub trait StaticPrefixSearch<D, I, const N: usize>: StaticBinarySearch<D, I, N>
+where
+    D: AsRef<[u8]>,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    /// Half-open range of positions of elements starting with prefix
+    fn prefix_range<P>(&self, data: &[D; N], prefix: &P) -> std::ops::Range<I>
+    where
+        P: AsRef<[u8]> + ?Sized,
+        Self: Sized,
+    {
+        let prefix: &[u8] = prefix.as_ref();
+        let start: I =
+            self.lower_bound_by(data, |element: &D| prefix_cmp(element.as_ref(), prefix));
+        let end: I = self.upper_bound_by(data, |element: &D| prefix_cmp(element.as_ref(), prefix));
+        return start..end;
+    }
+
+    /// Elements starting with prefix, in sorted order
+    fn prefix_iter<'a, P>(
+        &'a self,
+        data: &'a [D; N],
+        prefix: &P,
+    ) -> StaticPrefixIter<'a, D, I, Self, N>
+    where
+        P: AsRef<[u8]> + ?Sized,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        let range: std::ops::Range<I> = self.prefix_range(data, prefix);
+        return StaticPrefixIter {
+            bs: self,
+            data,
+            positions: cast(range.start).unwrap()..cast(range.end).unwrap(),
+            index_type: std::marker::PhantomData,
+        };
+    }
+}
+
+impl<D, I, B, const N: usize> StaticPrefixSearch<D, I, N> for B
+where
+    D: AsRef<[u8]>,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: StaticBinarySearch<D, I, N>,
+{
+}
+
+/// Iterator over elements of positions in range, located by the implementation
+pub struct StaticPrefixIter<'a, D, I, B, const N: usize> {
+    bs: &'a B,
+    data: &'a [D; N],
+    positions: std::ops::Range<usize>,
+    index_type: std::marker::PhantomData<I>,
+}
+
+impl<'a, D, I, B, const N: usize> StaticPrefixIter<'a, D, I, B, N>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: StaticBinarySearch<D, I, N>,
+{
+    #[inline]
+    fn element(&self, position: usize) -> &'a D {
+        use num_traits::cast::cast;
+
+        let location: I = self.bs.location(self.data, cast(position).unwrap());
+        return &self.data[cast::<I, usize>(location).unwrap()];
+    }
+}
+
+impl<'a, D, I, B, const N: usize> Iterator for StaticPrefixIter<'a, D, I, B, N>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: StaticBinarySearch<D, I, N>,
+{
+    type Item = &'a D;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a D> {
+        let position: usize = self.positions.next()?;
+        return Some(self.element(position));
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        return self.positions.size_hint();
+    }
+}
+
+impl<'a, D, I, B, const N: usize> DoubleEndedIterator for StaticPrefixIter<'a, D, I, B, N>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: StaticBinarySearch<D, I, N>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a D> {
+        let position: usize = self.positions.next_back()?;
+        return Some(self.element(position));
+    }
+}
+
+impl<'a, D, I, B, const N: usize> ExactSizeIterator for StaticPrefixIter<'a, D, I, B, N>
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: StaticBinarySearch<D, I, N>,
+{
+}
@@ -1,18 +1,21 @@
-use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
 
 trait Range<D, I>
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned + num::Integer,
 {
-    fn with_width(data: &[D], value: &D) -> Option<I>;
+    fn with_width(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn partition<F>(data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
 }
 
 pub struct Implementation;
 
 impl<D, I> Range<D, I> for Implementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -20,7 +23,10 @@ where
         + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn with_width(data: &[D], value: &D) -> Option<I> {
+    fn with_width(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let mut low: I = cast(0).unwrap();
@@ -39,11 +45,36 @@ where
             None
         };
     }
+
+    #[inline]
+    fn partition<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let mut low: I = cast(0).unwrap();
+        let n: I = cast(data.len()).unwrap(); //< This fails, if data length don't fit into type I
+        let mut width: I = n;
+        while width > cast(1).unwrap() {
+            width = (width + cast(1).unwrap()) >> 1u16; //< This fails, if width +1 don't fit into type I
+            let mid: I = low + width;
+            if (mid < n) && pred(&data[cast::<I, usize>(mid).unwrap()]) {
+                low = mid;
+            }
+        }
+        return if pred(&data[cast::<I, usize>(low).unwrap()]) {
+            low + cast(1).unwrap()
+        } else {
+            low
+        };
+    }
 }
 
+impl Layout for Implementation {}
+
 impl<D, I> DynamicBinarySearch<D, I> for Implementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -51,18 +82,102 @@ where
         + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if data.len() > 0 {
             Self::with_width(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            Self::partition(data, |element: &D| *element < *value)
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            Self::partition(data, |element: &D| *element <= *value)
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let position: I = Self::partition(data, |element: &D| *element < *value);
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if data.len() > 0 {
+            Self::partition(data, pred)
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = Self::partition(data, |element: &D| three_way.less(element));
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for Implementation
 where
-    D: std::cmp::PartialOrd,
     I: num_traits::Unsigned
         + num::Integer
         + num_traits::NumCast
@@ -70,11 +185,96 @@ where
         + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         return if N > 0 {
             Self::with_width(data, value)
         } else {
             None
         };
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            Self::partition(data, |element: &D| *element < *value)
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            Self::partition(data, |element: &D| *element <= *value)
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let position: I = Self::partition(data, |element: &D| *element < *value);
+            let index: usize = cast(position).unwrap();
+            if (index < N) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return if N > 0 {
+            Self::partition(data, pred)
+        } else {
+            cast(0).unwrap()
+        };
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = Self::partition(data, |element: &D| three_way.less(element));
+            let index: usize = cast(position).unwrap();
+            if (index < N) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
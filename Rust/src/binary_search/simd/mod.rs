@@ -0,0 +1,670 @@
+// K-ary search, which compares value against several separators at once with SIMD instructions.
+// Each round loads one separator per vector lane, splitting the searched range into lanes + 1
+// parts, and counts separators ordered before value; they are a prefix, as the array is sorted.
+// When the range is short enough, its elements are counted directly a vector at a time. The
+// instruction set is detected at runtime, and other targets use scalar counting. Searches without
+// a value, i.e. partition_point and search_by, are done by 'power'.
+
+use crate::binary_search::{power, DynamicBinarySearch, Layout};
+
+/// Instruction set used for comparisons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    /// No SIMD instructions
+    Scalar,
+    /// 128 bit vectors; there is no comparison of i64 before SSE4.2, so it is scalar
+    Sse2,
+    /// 256 bit vectors
+    Avx2,
+}
+
+impl Level {
+    /// The widest instruction set of this CPU
+    pub fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return Level::Avx2;
+            }
+            if is_x86_feature_detected!("sse2") {
+                return Level::Sse2;
+            }
+        }
+        return Level::Scalar;
+    }
+
+    /// All instruction sets of this CPU, from the narrowest
+    pub fn available() -> Vec<Self> {
+        let widest: Level = Level::detect();
+        return [Level::Scalar, Level::Sse2, Level::Avx2]
+            .into_iter()
+            .filter(|level: &Level| (*level as u8) <= (widest as u8))
+            .collect();
+    }
+
+    // Elements per vector
+    #[inline]
+    fn lanes<D>(self) -> usize {
+        return match self {
+            Level::Scalar => 4,
+            Level::Sse2 => 16 / std::mem::size_of::<D>(),
+            Level::Avx2 => 32 / std::mem::size_of::<D>(),
+        };
+    }
+}
+
+/// Keys, which are compared with SIMD instructions: i32, u32, f32 and i64
+pub trait Key: sealed::Count {}
+
+mod sealed {
+    // Counting is not public, as instructions of level must be available on this CPU
+    pub trait Count: std::cmp::PartialOrd + std::marker::Copy {
+        // Number of elements less than value, or not greater than value if inclusive
+        fn count(level: super::Level, block: &[Self], value: &Self, inclusive: bool) -> usize;
+
+        // As count, but of one vector of elements data[0], data[step], data[2 * step], ...
+        fn separators(
+            level: super::Level,
+            data: &[Self],
+            step: usize,
+            value: &Self,
+            inclusive: bool,
+        ) -> usize;
+    }
+}
+
+#[inline(always)]
+fn scalar_count<D>(block: &[D], value: &D, inclusive: bool) -> usize
+where
+    D: std::cmp::PartialOrd,
+{
+    if inclusive {
+        return block
+            .iter()
+            .filter(|element: &&D| **element <= *value)
+            .count();
+    }
+    return block
+        .iter()
+        .filter(|element: &&D| **element < *value)
+        .count();
+}
+
+#[inline(always)]
+fn scalar_separators<D>(level: Level, data: &[D], step: usize, value: &D, inclusive: bool) -> usize
+where
+    D: std::cmp::PartialOrd,
+{
+    return (0..level.lanes::<D>())
+        .filter(|lane: &usize| {
+            let element: &D = &data[lane * step];
+            if inclusive {
+                *element <= *value
+            } else {
+                *element < *value
+            }
+        })
+        .count();
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    // Block functions count whole vectors, i.e. length is a multiple of lanes, and separator
+    // functions read one element per lane, step apart. Unsigned 32 bit values are compared as
+    // signed ones after flipping the sign bit.
+    use super::{Key, Level};
+    use std::arch::x86_64::*;
+
+    // Whole search is compiled with the instructions, so that the counting is inlined
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn partition_sse2<D>(data: &[D], value: &D, inclusive: bool) -> usize
+    where
+        D: Key,
+    {
+        return super::partition(Level::Sse2, data, value, inclusive);
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn partition_avx2<D>(data: &[D], value: &D, inclusive: bool) -> usize
+    where
+        D: Key,
+    {
+        return super::partition(Level::Avx2, data, value, inclusive);
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn less_epi32_sse2(x: __m128i, v: __m128i, inclusive: bool) -> usize {
+        let mask: i32 = if inclusive {
+            !_mm_movemask_ps(_mm_castsi128_ps(_mm_cmpgt_epi32(x, v))) & 0xF
+        } else {
+            _mm_movemask_ps(_mm_castsi128_ps(_mm_cmplt_epi32(x, v)))
+        };
+        return mask.count_ones() as usize;
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn epi32_sse2(
+        data: *const i32,
+        len: usize,
+        value: i32,
+        flip: i32,
+        inclusive: bool,
+    ) -> usize {
+        let f: __m128i = _mm_set1_epi32(flip);
+        let v: __m128i = _mm_set1_epi32(value ^ flip);
+        let mut count: usize = 0;
+        let mut index: usize = 0;
+        while index < len {
+            let x: __m128i = _mm_loadu_si128(data.add(index) as *const __m128i);
+            count += less_epi32_sse2(_mm_xor_si128(x, f), v, inclusive);
+            index += 4;
+        }
+        return count;
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn separators_epi32_sse2(
+        data: *const i32,
+        step: usize,
+        value: i32,
+        flip: i32,
+        inclusive: bool,
+    ) -> usize {
+        let x: __m128i = _mm_set_epi32(
+            *data.add(3 * step),
+            *data.add(2 * step),
+            *data.add(step),
+            *data,
+        );
+        let f: __m128i = _mm_set1_epi32(flip);
+        let v: __m128i = _mm_set1_epi32(value ^ flip);
+        return less_epi32_sse2(_mm_xor_si128(x, f), v, inclusive);
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn less_epi32_avx2(x: __m256i, v: __m256i, inclusive: bool) -> usize {
+        let mask: i32 = if inclusive {
+            !_mm256_movemask_ps(_mm256_castsi256_ps(_mm256_cmpgt_epi32(x, v))) & 0xFF
+        } else {
+            _mm256_movemask_ps(_mm256_castsi256_ps(_mm256_cmpgt_epi32(v, x)))
+        };
+        return mask.count_ones() as usize;
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn epi32_avx2(
+        data: *const i32,
+        len: usize,
+        value: i32,
+        flip: i32,
+        inclusive: bool,
+    ) -> usize {
+        let f: __m256i = _mm256_set1_epi32(flip);
+        let v: __m256i = _mm256_set1_epi32(value ^ flip);
+        let mut count: usize = 0;
+        let mut index: usize = 0;
+        while index < len {
+            let x: __m256i = _mm256_loadu_si256(data.add(index) as *const __m256i);
+            count += less_epi32_avx2(_mm256_xor_si256(x, f), v, inclusive);
+            index += 8;
+        }
+        return count;
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn separators_epi32_avx2(
+        data: *const i32,
+        step: usize,
+        value: i32,
+        flip: i32,
+        inclusive: bool,
+    ) -> usize {
+        let x: __m256i = _mm256_set_epi32(
+            *data.add(7 * step),
+            *data.add(6 * step),
+            *data.add(5 * step),
+            *data.add(4 * step),
+            *data.add(3 * step),
+            *data.add(2 * step),
+            *data.add(step),
+            *data,
+        );
+        let f: __m256i = _mm256_set1_epi32(flip);
+        let v: __m256i = _mm256_set1_epi32(value ^ flip);
+        return less_epi32_avx2(_mm256_xor_si256(x, f), v, inclusive);
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn less_ps_sse2(x: __m128, v: __m128, inclusive: bool) -> usize {
+        let mask: i32 = if inclusive {
+            _mm_movemask_ps(_mm_cmple_ps(x, v))
+        } else {
+            _mm_movemask_ps(_mm_cmplt_ps(x, v))
+        };
+        return mask.count_ones() as usize;
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn ps_sse2(
+        data: *const f32,
+        len: usize,
+        value: f32,
+        inclusive: bool,
+    ) -> usize {
+        let v: __m128 = _mm_set1_ps(value);
+        let mut count: usize = 0;
+        let mut index: usize = 0;
+        while index < len {
+            count += less_ps_sse2(_mm_loadu_ps(data.add(index)), v, inclusive);
+            index += 4;
+        }
+        return count;
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn separators_ps_sse2(
+        data: *const f32,
+        step: usize,
+        value: f32,
+        inclusive: bool,
+    ) -> usize {
+        let x: __m128 = _mm_set_ps(
+            *data.add(3 * step),
+            *data.add(2 * step),
+            *data.add(step),
+            *data,
+        );
+        return less_ps_sse2(x, _mm_set1_ps(value), inclusive);
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn less_ps_avx2(x: __m256, v: __m256, inclusive: bool) -> usize {
+        let mask: i32 = if inclusive {
+            _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_LE_OQ>(x, v))
+        } else {
+            _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_LT_OQ>(x, v))
+        };
+        return mask.count_ones() as usize;
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn ps_avx2(
+        data: *const f32,
+        len: usize,
+        value: f32,
+        inclusive: bool,
+    ) -> usize {
+        let v: __m256 = _mm256_set1_ps(value);
+        let mut count: usize = 0;
+        let mut index: usize = 0;
+        while index < len {
+            count += less_ps_avx2(_mm256_loadu_ps(data.add(index)), v, inclusive);
+            index += 8;
+        }
+        return count;
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn separators_ps_avx2(
+        data: *const f32,
+        step: usize,
+        value: f32,
+        inclusive: bool,
+    ) -> usize {
+        let x: __m256 = _mm256_set_ps(
+            *data.add(7 * step),
+            *data.add(6 * step),
+            *data.add(5 * step),
+            *data.add(4 * step),
+            *data.add(3 * step),
+            *data.add(2 * step),
+            *data.add(step),
+            *data,
+        );
+        return less_ps_avx2(x, _mm256_set1_ps(value), inclusive);
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn less_epi64_avx2(x: __m256i, v: __m256i, inclusive: bool) -> usize {
+        let mask: i32 = if inclusive {
+            !_mm256_movemask_pd(_mm256_castsi256_pd(_mm256_cmpgt_epi64(x, v))) & 0xF
+        } else {
+            _mm256_movemask_pd(_mm256_castsi256_pd(_mm256_cmpgt_epi64(v, x)))
+        };
+        return mask.count_ones() as usize;
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn epi64_avx2(
+        data: *const i64,
+        len: usize,
+        value: i64,
+        inclusive: bool,
+    ) -> usize {
+        let v: __m256i = _mm256_set1_epi64x(value);
+        let mut count: usize = 0;
+        let mut index: usize = 0;
+        while index < len {
+            let x: __m256i = _mm256_loadu_si256(data.add(index) as *const __m256i);
+            count += less_epi64_avx2(x, v, inclusive);
+            index += 4;
+        }
+        return count;
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn separators_epi64_avx2(
+        data: *const i64,
+        step: usize,
+        value: i64,
+        inclusive: bool,
+    ) -> usize {
+        let x: __m256i = _mm256_set_epi64x(
+            *data.add(3 * step),
+            *data.add(2 * step),
+            *data.add(step),
+            *data,
+        );
+        return less_epi64_avx2(x, _mm256_set1_epi64x(value), inclusive);
+    }
+}
+
+// Levels are checked when implementation is created, so the instructions are available, and
+// partition passes separator slices long enough for each lane
+impl sealed::Count for i32 {
+    #[inline(always)]
+    fn count(level: Level, block: &[i32], value: &i32, inclusive: bool) -> usize {
+        let full: usize = block.len() - block.len() % level.lanes::<i32>();
+        let count: usize = match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe { x86::epi32_sse2(block.as_ptr(), full, *value, 0, inclusive) },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe { x86::epi32_avx2(block.as_ptr(), full, *value, 0, inclusive) },
+            _ => scalar_count(&block[..full], value, inclusive),
+        };
+        return count + scalar_count(&block[full..], value, inclusive);
+    }
+
+    #[inline(always)]
+    fn separators(level: Level, data: &[i32], step: usize, value: &i32, inclusive: bool) -> usize {
+        return match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe {
+                x86::separators_epi32_sse2(data.as_ptr(), step, *value, 0, inclusive)
+            },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe {
+                x86::separators_epi32_avx2(data.as_ptr(), step, *value, 0, inclusive)
+            },
+            _ => scalar_separators(level, data, step, value, inclusive),
+        };
+    }
+}
+
+impl sealed::Count for u32 {
+    #[inline(always)]
+    fn count(level: Level, block: &[u32], value: &u32, inclusive: bool) -> usize {
+        const FLIP: i32 = i32::MIN;
+        let full: usize = block.len() - block.len() % level.lanes::<u32>();
+        let data: *const i32 = block.as_ptr() as *const i32;
+        let signed: i32 = *value as i32;
+        let count: usize = match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe { x86::epi32_sse2(data, full, signed, FLIP, inclusive) },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe { x86::epi32_avx2(data, full, signed, FLIP, inclusive) },
+            _ => scalar_count(&block[..full], value, inclusive),
+        };
+        return count + scalar_count(&block[full..], value, inclusive);
+    }
+
+    #[inline(always)]
+    fn separators(level: Level, data: &[u32], step: usize, value: &u32, inclusive: bool) -> usize {
+        const FLIP: i32 = i32::MIN;
+        let signed: i32 = *value as i32;
+        return match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe {
+                x86::separators_epi32_sse2(
+                    data.as_ptr() as *const i32,
+                    step,
+                    signed,
+                    FLIP,
+                    inclusive,
+                )
+            },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe {
+                x86::separators_epi32_avx2(
+                    data.as_ptr() as *const i32,
+                    step,
+                    signed,
+                    FLIP,
+                    inclusive,
+                )
+            },
+            _ => scalar_separators(level, data, step, value, inclusive),
+        };
+    }
+}
+
+impl sealed::Count for f32 {
+    #[inline(always)]
+    fn count(level: Level, block: &[f32], value: &f32, inclusive: bool) -> usize {
+        let full: usize = block.len() - block.len() % level.lanes::<f32>();
+        let count: usize = match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe { x86::ps_sse2(block.as_ptr(), full, *value, inclusive) },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe { x86::ps_avx2(block.as_ptr(), full, *value, inclusive) },
+            _ => scalar_count(&block[..full], value, inclusive),
+        };
+        return count + scalar_count(&block[full..], value, inclusive);
+    }
+
+    #[inline(always)]
+    fn separators(level: Level, data: &[f32], step: usize, value: &f32, inclusive: bool) -> usize {
+        return match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe {
+                x86::separators_ps_sse2(data.as_ptr(), step, *value, inclusive)
+            },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe {
+                x86::separators_ps_avx2(data.as_ptr(), step, *value, inclusive)
+            },
+            _ => scalar_separators(level, data, step, value, inclusive),
+        };
+    }
+}
+
+impl sealed::Count for i64 {
+    #[inline(always)]
+    fn count(level: Level, block: &[i64], value: &i64, inclusive: bool) -> usize {
+        let full: usize = block.len() - block.len() % level.lanes::<i64>();
+        let count: usize = match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe { x86::epi64_avx2(block.as_ptr(), full, *value, inclusive) },
+            _ => scalar_count(&block[..full], value, inclusive),
+        };
+        return count + scalar_count(&block[full..], value, inclusive);
+    }
+
+    #[inline(always)]
+    fn separators(level: Level, data: &[i64], step: usize, value: &i64, inclusive: bool) -> usize {
+        return match level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe {
+                x86::separators_epi64_avx2(data.as_ptr(), step, *value, inclusive)
+            },
+            _ => scalar_separators(level, data, step, value, inclusive),
+        };
+    }
+}
+
+impl Key for i32 {}
+impl Key for u32 {}
+impl Key for f32 {}
+impl Key for i64 {}
+
+#[inline(always)]
+fn partition<D>(level: Level, data: &[D], value: &D, inclusive: bool) -> usize
+where
+    D: Key,
+{
+    let lanes: usize = level.lanes::<D>();
+    let mut low: usize = 0;
+    let mut high: usize = data.len();
+    // Partition point is within [low, high], and separators split it into lanes + 1 parts; the
+    // last separator is at low + lanes * step - 1 < high
+    while high - low > (lanes << 2) {
+        let step: usize = (high - low) / (lanes + 1);
+        let first: usize = low + step - 1;
+        let count: usize = D::separators(level, &data[first..high], step, value, inclusive);
+        high = if count < lanes {
+            first + count * step
+        } else {
+            high
+        };
+        low += count * step;
+    }
+    return low + D::count(level, &data[low..high], value, inclusive);
+}
+
+/// K-ary search with the instructions of given level
+pub struct Implementation {
+    level: Level,
+}
+
+impl Implementation {
+    /// The widest instructions of this CPU
+    pub fn new() -> Self {
+        return Implementation {
+            level: Level::detect(),
+        };
+    }
+
+    /// Given instructions, if this CPU has them
+    pub fn with_level(level: Level) -> Option<Self> {
+        if Level::available().contains(&level) {
+            return Some(Implementation { level });
+        }
+        return None;
+    }
+
+    /// Instructions used for comparisons
+    pub fn level(&self) -> Level {
+        return self.level;
+    }
+
+    #[inline]
+    fn partition<D>(&self, data: &[D], value: &D, inclusive: bool) -> usize
+    where
+        D: Key,
+    {
+        // Levels are checked when implementation is created, so the instructions are available
+        return match self.level {
+            #[cfg(target_arch = "x86_64")]
+            Level::Sse2 => unsafe { x86::partition_sse2(data, value, inclusive) },
+            #[cfg(target_arch = "x86_64")]
+            Level::Avx2 => unsafe { x86::partition_avx2(data, value, inclusive) },
+            _ => partition(Level::Scalar, data, value, inclusive),
+        };
+    }
+}
+
+impl Default for Implementation {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Layout for Implementation {}
+
+impl<D, I> DynamicBinarySearch<D, I> for Implementation
+where
+    D: Key,
+    I: num_traits::Unsigned
+        + num::Integer
+        + num_traits::NumCast
+        + std::marker::Copy
+        + std::ops::BitOr<Output = I>
+        + std::ops::ShrAssign<u16>,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return cast(self.partition(data, value, false)).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return cast(self.partition(data, value, true)).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let index: usize = self.partition(data, value, false);
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        return bs.partition_point(data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        return bs.search_by(data, f);
+    }
+}
@@ -0,0 +1,337 @@
+// Static B-tree (S-tree) layout, where each node holds KEYS sorted keys, i.e. one cache line of
+// 32 bit keys, and has KEYS + 1 children. Like Eytzinger layout, the tree is implicit: children of
+// node k are nodes k * (KEYS + 1) + 1 ... k * (KEYS + 1) + KEYS + 1, and keys of node k are at
+// k * KEYS ... k * KEYS + KEYS - 1. Only the last node may be partial, and it has no children.
+// A search counts the keys of each node that are ordered before value, without branches, and the
+// count selects the child; so one cache line is touched per level of a tree of height log17(n).
+// This is a B-tree, not the B+ tree of S+-trees: each key is stored once, at any level, instead of
+// all keys in the leaves and copies of some as separators in the internal nodes. That way the
+// layout is a permutation of the monotonic array, like Eytzinger and van Emde Boas layouts, so it
+// is searched through the same traits, and locations of found elements index the searched array.
+// A B+ tree would need about n / KEYS extra keys and a longer array than the data it holds.
+
+/// Keys per node
+pub const KEYS: usize = 16;
+
+pub mod utility {
+    use super::KEYS;
+
+    //
+    // Some helper functions
+    //
+
+    fn recursive_stree_layout<D: std::clone::Clone>(
+        stree: &mut [D],
+        monotonic: &[D],
+        mut i: usize,
+        node: usize,
+    ) -> usize {
+        if node * KEYS < stree.len() {
+            let first_child: usize = node * (KEYS + 1) + 1;
+            for slot in 0..KEYS {
+                i = recursive_stree_layout(stree, monotonic, i, first_child + slot);
+                if node * KEYS + slot < stree.len() {
+                    stree[node * KEYS + slot] = monotonic[i].clone();
+                    i += 1usize;
+                }
+            }
+            i = recursive_stree_layout(stree, monotonic, i, first_child + KEYS);
+        }
+
+        return i;
+    }
+
+    // Height of S-tree of n elements, and the first node of its last level
+    fn last_level(n: usize) -> (u32, usize) {
+        let mut height: u32 = 1;
+        let mut first: usize = 0;
+        while (first * (KEYS + 1) + 1) * KEYS < n {
+            first = first * (KEYS + 1) + 1;
+            height += 1;
+        }
+        return (height, first);
+    }
+
+    // Depth of node, and the first node of that level
+    fn level(node: usize) -> (u32, usize) {
+        let mut depth: u32 = 0;
+        let mut first: usize = 0;
+        while first * (KEYS + 1) < node {
+            first = first * (KEYS + 1) + 1;
+            depth += 1;
+        }
+        return (depth, first);
+    }
+
+    #[inline]
+    pub fn stree_layout<D: std::clone::Clone>(stree: &mut [D], monotonic: &[D]) -> bool {
+        if stree.len() != monotonic.len() {
+            return false;
+        }
+
+        let length: usize = recursive_stree_layout(stree, monotonic, 0, 0);
+
+        return length == monotonic.len();
+    }
+
+    // Rank (index in monotonic array) of element at given index of S-tree layout of n elements
+    #[inline]
+    pub fn stree_rank(n: usize, index: usize) -> usize {
+        let (height, last_first): (u32, usize) = last_level(n);
+        let last_keys: usize = n - last_first * KEYS;
+        let (depth, first): (u32, usize) = level(index / KEYS);
+        let offset: usize = index / KEYS - first;
+        // In-order position within perfect tree of same height; keys above the last level are
+        // at positions p, for which p + 1 is a multiple of KEYS + 1
+        let position: usize =
+            (offset * (KEYS + 1) + index % KEYS + 1) * (KEYS + 1).pow(height - 1 - depth) - 1;
+        // Skip the keys missing from the last level
+        let missing: usize = (position - position / (KEYS + 1)).saturating_sub(last_keys);
+        return position - missing;
+    }
+
+    // Index in S-tree layout of n elements for given rank (index in monotonic array, below n)
+    #[inline]
+    pub fn stree_index(n: usize, rank: usize) -> usize {
+        let (height, last_first): (u32, usize) = last_level(n);
+        let last_keys: usize = n - last_first * KEYS;
+        // In-order position (one based) within perfect tree of same height
+        let mut position: usize = if rank < last_keys + last_keys / KEYS {
+            rank + 1
+        } else {
+            (rank - last_keys + 1) * (KEYS + 1)
+        };
+        let mut depth: u32 = height - 1;
+        while position.is_multiple_of(KEYS + 1) {
+            position /= KEYS + 1;
+            depth -= 1;
+        }
+        let mut first: usize = 0;
+        for _ in 0..depth {
+            first = first * (KEYS + 1) + 1;
+        }
+        return (first + position / (KEYS + 1)) * KEYS + position % (KEYS + 1) - 1;
+    }
+}
+
+use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+
+/// Search of S-tree layout
+pub struct Implementation;
+
+// Number of keys of node for which predicate is true. Keys are probed from right to left, so the
+// last probe where predicate was false is at the found position, as ThreeWay expects.
+#[inline]
+fn count<D, F>(keys: &[D], pred: &mut F) -> usize
+where
+    F: FnMut(&D) -> bool,
+{
+    let mut count: usize = 0;
+    for key in keys.iter().rev() {
+        count += pred(key) as usize;
+    }
+    return count;
+}
+
+// Index in layout and rank of the first element for which predicate is false, or n if there is
+// none. The rank is computed like utility::stree_rank, but from the path of the search.
+#[inline]
+fn partition<D, F>(data: &[D], mut pred: F) -> (usize, usize)
+where
+    F: FnMut(&D) -> bool,
+{
+    let n: usize = data.len();
+    let mut index: usize = n;
+    // In-order position (one based) of the element within perfect tree of levels searched so far
+    let mut position: usize = 0;
+    let mut scale: usize = 1;
+    let mut level: usize = 0;
+    let mut previous_level: usize = 0;
+    let mut node: usize = 0;
+    while node * KEYS < n {
+        let first: usize = node * KEYS;
+        let keys: usize = KEYS.min(n - first);
+        // Full nodes have constant length, so that the scan may be unrolled or vectorized
+        let count: usize = if keys == KEYS {
+            let full: &[D; KEYS] = data[first..first + KEYS].try_into().unwrap();
+            count(full, &mut pred)
+        } else {
+            count(&data[first..], &mut pred)
+        };
+        // Elements of the child are ordered between keys count - 1 and count of node
+        let found: bool = count < keys;
+        index = if found { first + count } else { index };
+        position = if found {
+            (node - level) * (KEYS + 1) + count + 1
+        } else {
+            position
+        };
+        scale = if found { 1 } else { scale * (KEYS + 1) };
+        previous_level = level;
+        level = level * (KEYS + 1) + 1;
+        node = node * (KEYS + 1) + count + 1;
+    }
+    if index == n {
+        return (n, n);
+    }
+    // Search ended at a missing node, which is either below the last level or on it
+    let last_level: usize = if level * KEYS < n {
+        scale *= KEYS + 1;
+        level
+    } else {
+        previous_level
+    };
+    let position: usize = position * scale - 1;
+    // Skip the keys missing from the last level
+    let missing: usize = (position - position / (KEYS + 1)).saturating_sub(n - last_level * KEYS);
+    return (index, position - missing);
+}
+
+impl<D, I> DynamicBinarySearch<D, I> for Implementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let (index, _): (usize, usize) = partition(data, |element: &D| *element < *value);
+        return if (index < data.len()) && (data[index] == *value) {
+            Some(cast(index).unwrap())
+        } else {
+            None
+        };
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return cast(partition(data, |element: &D| *element < *value).1).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return cast(partition(data, |element: &D| *element <= *value).1).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let (index, rank): (usize, usize) = partition(data, |element: &D| *element < *value);
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(rank).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return cast(partition(data, pred).1).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let (index, rank): (usize, usize) = partition(data, |element: &D| three_way.less(element));
+        if (index < data.len()) && three_way.found() {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(rank).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, data: &[D], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::stree_index(data.len(), cast(position).unwrap())).unwrap();
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for Implementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::r#impl(self, data, value);
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::lower_bound(self, data, value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::upper_bound(self, data, value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::partition_point(self, data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::search_by(self, data, f);
+    }
+
+    #[inline]
+    fn location(&self, data: &[D; N], position: I) -> I {
+        return DynamicBinarySearch::location(self, data, position);
+    }
+}
@@ -1,11 +1,15 @@
-use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
 
 trait Traditional<D, I>
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer,
 {
-    fn two_conditions(data: &[D], value: &D) -> Option<I>;
+    fn two_conditions(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd;
+    fn partition<F>(data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool;
 }
 
 pub struct SignedImplementation;
@@ -14,11 +18,13 @@ pub struct UnsignedImplementation;
 
 impl<D, I> Traditional<D, I> for SignedImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer + num_traits::NumCast + std::marker::Copy + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn two_conditions(data: &[D], value: &D) -> Option<I> {
+    fn two_conditions(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let mut low: I = cast(0).unwrap();
@@ -36,15 +42,38 @@ where
         }
         return None;
     }
+
+    #[inline]
+    fn partition<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let mut low: I = cast(0).unwrap();
+        let mut high: I = cast(data.len() - 1).unwrap();
+        while low <= high {
+            let mid: I = low + ((high - low) >> 1u16);
+            let index: usize = cast(mid).unwrap();
+            if pred(&data[index]) {
+                low = mid + cast(1).unwrap();
+            } else {
+                high = mid - cast(1).unwrap();
+            }
+        }
+        return low;
+    }
 }
 
 impl<D, I> Traditional<D, I> for UnsignedImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer + num_traits::NumCast + std::marker::Copy + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn two_conditions(data: &[D], value: &D) -> Option<I> {
+    fn two_conditions(data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         use num_traits::cast::cast;
 
         let mut low: I = cast(0).unwrap();
@@ -66,60 +95,413 @@ where
         }
         return None;
     }
+
+    #[inline]
+    fn partition<F>(data: &[D], mut pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+    {
+        use num_traits::cast::cast;
+
+        let mut low: I = cast(0).unwrap();
+        let mut high: I = cast(data.len() - 1usize).unwrap();
+        while low < high {
+            let mid: I = low + ((high - low + cast(1).unwrap()) >> 1u16);
+            let index: usize = cast(mid).unwrap();
+            if pred(&data[index]) {
+                low = mid + cast(1).unwrap();
+            } else {
+                high = mid - cast(1).unwrap();
+            }
+        }
+        let index: usize = cast(low).unwrap();
+        if (index < data.len()) && pred(&data[index]) {
+            return low + cast(1).unwrap();
+        }
+        return low;
+    }
 }
 
+impl Layout for SignedImplementation {}
+
 impl<D, I> DynamicBinarySearch<D, I> for SignedImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer + num_traits::NumCast + std::marker::Copy + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         if data.len() > 0 {
             return Self::two_conditions(data, value);
         }
         return None;
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            return Self::partition(data, |element: &D| *element < *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            return Self::partition(data, |element: &D| *element <= *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let position: I = Self::partition(data, |element: &D| *element < *value);
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            return Self::partition(data, pred);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = Self::partition(data, |element: &D| three_way.less(element));
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for SignedImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer + num_traits::NumCast + std::marker::Copy + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         if N > 0 {
             return Self::two_conditions(data.as_slice(), value);
         }
         return None;
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            return Self::partition(data.as_slice(), |element: &D| *element < *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            return Self::partition(data.as_slice(), |element: &D| *element <= *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let position: I = Self::partition(data, |element: &D| *element < *value);
+            let index: usize = cast(position).unwrap();
+            if (index < N) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            return Self::partition(data, pred);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = Self::partition(data, |element: &D| three_way.less(element));
+            let index: usize = cast(position).unwrap();
+            if (index < N) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
 
+impl Layout for UnsignedImplementation {}
+
 impl<D, I> DynamicBinarySearch<D, I> for UnsignedImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer + num_traits::NumCast + std::marker::Copy + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         if data.len() > 0 {
             return Self::two_conditions(data, value);
         }
         return None;
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            return Self::partition(data, |element: &D| *element < *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            return Self::partition(data, |element: &D| *element <= *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let position: I = Self::partition(data, |element: &D| *element < *value);
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            return Self::partition(data, pred);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if data.len() > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = Self::partition(data, |element: &D| three_way.less(element));
+            let index: usize = cast(position).unwrap();
+            if (index < data.len()) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
 
 impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for UnsignedImplementation
 where
-    D: std::cmp::PartialOrd,
     I: num::Integer + num_traits::NumCast + std::marker::Copy + std::ops::Shr<u16, Output = I>,
 {
     #[inline]
-    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I> {
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
         if N > 0 {
             return Self::two_conditions(data, value);
         }
         return None;
     }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            return Self::partition(data.as_slice(), |element: &D| *element < *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            return Self::partition(data.as_slice(), |element: &D| *element <= *value);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let position: I = Self::partition(data, |element: &D| *element < *value);
+            let index: usize = cast(position).unwrap();
+            if (index < N) && (data[index] == *value) {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            return Self::partition(data, pred);
+        }
+        return cast(0).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        if N > 0 {
+            let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+            let position: I = Self::partition(data, |element: &D| three_way.less(element));
+            let index: usize = cast(position).unwrap();
+            if (index < N) && three_way.found() {
+                return Ok(position);
+            }
+            return Err(position);
+        }
+        return Err(cast(0).unwrap());
+    }
 }
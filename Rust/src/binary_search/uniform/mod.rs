@@ -0,0 +1,263 @@
+// Uniform binary search by Knuth (The Art of Computer Programming, Vol. 3, 6.2.1, algorithm C).
+// Instead of low and high, there is only the current probe, which moves by precomputed deltas:
+// DELTA[j] = floor((N + 2^(j-1)) / 2^j), j = 1, 2, ... until zero. The deltas depend only on the
+// length of the array, so the loop has no division, shift or subtraction of bounds. As any
+// comparison tree search, the probes bound the final gap, so the probe before the result is the
+// last probe for which predicate was true.
+
+use crate::binary_search::{DynamicBinarySearch, Layout, StaticBinarySearch};
+
+/// Length of delta tables, the last delta is always zero
+pub const DELTAS: usize = usize::BITS as usize + 1;
+
+/// Deltas of uniform binary search for an array of n elements
+pub const fn deltas(n: usize) -> [usize; DELTAS] {
+    let mut table: [usize; DELTAS] = [0; DELTAS];
+    let mut k: usize = 0;
+    while k < usize::BITS as usize {
+        // Rounded half up n / 2^(k+1), without overflow of n + 2^k
+        let half: usize = if k + 1 < usize::BITS as usize {
+            n >> (k + 1)
+        } else {
+            0
+        };
+        table[k] = half + ((n >> k) & 1);
+        k += 1;
+    }
+    return table;
+}
+
+// Delta table of compile time constant length
+struct Deltas<const N: usize>;
+
+impl<const N: usize> Deltas<N> {
+    const TABLE: [usize; DELTAS] = deltas(N);
+}
+
+/// Uniform binary search with delta table for arrays of given length
+pub struct DynamicImplementation {
+    len: usize,
+    deltas: [usize; DELTAS],
+}
+
+impl DynamicImplementation {
+    /// Deltas for arrays of given length; arrays of other lengths compute them for each search
+    pub fn new(len: usize) -> Self {
+        return DynamicImplementation {
+            len,
+            deltas: deltas(len),
+        };
+    }
+
+    #[inline]
+    fn with_deltas<D, R, S>(&self, data: &[D], search: S) -> R
+    where
+        S: FnOnce(&[D], &[usize; DELTAS]) -> R,
+    {
+        if data.len() == self.len {
+            return search(data, &self.deltas);
+        }
+        return search(data, &deltas(data.len()));
+    }
+}
+
+/// Uniform binary search with delta table computed at compile time
+pub struct StaticImplementation;
+
+#[inline]
+fn partition<D, F>(data: &[D], deltas: &[usize; DELTAS], mut pred: F) -> usize
+where
+    F: FnMut(&D) -> bool,
+{
+    if data.len() == 0 {
+        return 0;
+    }
+    // One-based index of probe, as in algorithm C
+    let mut index: usize = deltas[0];
+    let mut j: usize = 1;
+    loop {
+        // For even lengths the probe may move before the first element, to K0 = -inf of Knuth
+        let right: bool = (index == 0) || pred(&data[index - 1]);
+        let delta: usize = deltas[j];
+        if delta == 0 {
+            return index + (right as usize) - 1;
+        }
+        if right {
+            index += delta;
+        } else {
+            index -= delta;
+        }
+        j += 1;
+    }
+}
+
+impl Layout for DynamicImplementation {}
+
+impl<D, I> DynamicBinarySearch<D, I> for DynamicImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = self.with_deltas(data, |data: &[D], deltas: &[usize; DELTAS]| {
+            partition(data, deltas, |element: &D| *element < *value)
+        });
+        return cast(position).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = self.with_deltas(data, |data: &[D], deltas: &[usize; DELTAS]| {
+            partition(data, deltas, |element: &D| *element <= *value)
+        });
+        return cast(position).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let index: usize = self.with_deltas(data, |data: &[D], deltas: &[usize; DELTAS]| {
+            partition(data, deltas, |element: &D| *element < *value)
+        });
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = self.with_deltas(data, |data: &[D], deltas: &[usize; DELTAS]| {
+            partition(data, deltas, pred)
+        });
+        return cast(position).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let index: usize = self.with_deltas(data, |data: &[D], deltas: &[usize; DELTAS]| {
+            partition(data, deltas, |element: &D| three_way.less(element))
+        });
+        if (index < data.len()) && three_way.found() {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for StaticImplementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return StaticBinarySearch::search(self, data, value).ok();
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = partition(data, &Deltas::<N>::TABLE, |element: &D| *element < *value);
+        return cast(position).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize =
+            partition(data, &Deltas::<N>::TABLE, |element: &D| *element <= *value);
+        return cast(position).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let index: usize = partition(data, &Deltas::<N>::TABLE, |element: &D| *element < *value);
+        if (index < N) && (data[index] == *value) {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        let position: usize = partition(data, &Deltas::<N>::TABLE, pred);
+        return cast(position).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let index: usize = partition(data, &Deltas::<N>::TABLE, |element: &D| {
+            three_way.less(element)
+        });
+        if (index < N) && three_way.found() {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(index).unwrap());
+    }
+}
@@ -0,0 +1,351 @@
+// Cache-oblivious van Emde Boas layout of the same binary search tree as Eytzinger layout. A tree
+// of height h is split into a top tree of h / 2 levels and bottom trees of the remaining levels;
+// the top tree is stored first and the bottom trees after it from left to right, each split again
+// recursively. So any subtree of height 2^k is stored contiguously, and a search touches about
+// log_B(n) blocks of size B, whatever B is. Nodes missing from the last level take no space.
+// A search walks the tree like Eytzinger search, locating each node in layout from the locations
+// of its ancestors.
+
+pub mod utility {
+    use crate::binary_search::eytzinger::utility::{eytzinger_index, eytzinger_rank};
+
+    //
+    // Some helper functions
+    //
+
+    // Splits of the subtree of given height, rooted at given depth
+    const fn split(splits: &mut [(u8, u8); 64], root: usize, height: usize) {
+        if height > 1 {
+            let top: usize = height / 2;
+            splits[root + top] = (root as u8, (height - top) as u8);
+            split(splits, root, top);
+            split(splits, root + top, height - top);
+        }
+    }
+
+    const fn splits() -> [[(u8, u8); 64]; 65] {
+        let mut splits: [[(u8, u8); 64]; 65] = [[(0, 0); 64]; 65];
+        let mut height: usize = 0;
+        while height <= 64 {
+            split(&mut splits[height], 0, height);
+            height += 1;
+        }
+        return splits;
+    }
+
+    // In tree of height h, each depth d > 0 is the depth of the roots of bottom trees of exactly
+    // one split. SPLITS[h][d] is the depth of the root of that split and height of its bottom trees.
+    const SPLITS: [[(u8, u8); 64]; 65] = splits();
+
+    // Height of tree of n elements, also levels from root to node n
+    #[inline]
+    pub(super) fn height(n: usize) -> usize {
+        return (usize::BITS - n.leading_zeros()) as usize;
+    }
+
+    // Number of nodes missing from the last level of the subtrees rooted at given nodes
+    #[inline]
+    fn missing(n: usize, height: usize, depth: usize, nodes: std::ops::Range<usize>) -> usize {
+        let level: usize = height - 1 - depth;
+        return (nodes.end << level).saturating_sub((nodes.start << level).max(n + 1));
+    }
+
+    // Index in layout of node (one based) at depth > 0, given the indices of its ancestors
+    #[inline]
+    pub(super) fn locate(
+        n: usize,
+        height: usize,
+        ancestors: &[usize; 64],
+        node: usize,
+        depth: usize,
+    ) -> usize {
+        let (root, bottom): (u8, u8) = SPLITS[height][depth];
+        let (root, bottom): (usize, usize) = (root as usize, bottom as usize);
+        let top: usize = depth - root;
+        // Bottom trees before the one of node
+        let before: usize = node & ((1usize << top) - 1);
+        let index: usize =
+            ancestors[root] + (1usize << top) - 1 + before * ((1usize << bottom) - 1);
+        return if depth + bottom == height {
+            index - missing(n, height, depth, node - before..node)
+        } else {
+            index
+        };
+    }
+
+    // Index in layout of n elements of node (one based)
+    fn node_index(n: usize, node: usize) -> usize {
+        let height: usize = height(n);
+        let depth: usize = usize::BITS as usize - 1 - node.leading_zeros() as usize;
+        let mut ancestors: [usize; 64] = [0; 64];
+        for d in 1..=depth {
+            ancestors[d] = locate(n, height, &ancestors, node >> (depth - d), d);
+        }
+        return ancestors[depth];
+    }
+
+    // Node (one based) at given index of layout of n elements
+    fn index_node(n: usize, index: usize) -> usize {
+        let height: usize = height(n);
+        let mut root: usize = 1;
+        let mut depth: usize = 0;
+        let mut levels: usize = height;
+        let mut first: usize = 0;
+        while index != first {
+            let top: usize = levels / 2;
+            let top_size: usize = (1usize << top) - 1;
+            if index < first + top_size {
+                levels = top;
+                continue;
+            }
+            // Bottom tree containing index, sizes of bottom trees before it being known
+            let bottom_size: usize = (1usize << (levels - top)) - 1;
+            let offset: usize = index - first - top_size;
+            let roots: usize = root << top;
+            let before = |trees: usize| -> usize {
+                let size: usize = trees * bottom_size;
+                return if depth + levels == height {
+                    size - missing(n, height, depth + top, roots..roots + trees)
+                } else {
+                    size
+                };
+            };
+            // The last tree starting at or before offset is not empty
+            let (mut low, mut high): (usize, usize) = (0, 1usize << top);
+            while low + 1 < high {
+                let middle: usize = low + (high - low) / 2;
+                if before(middle) <= offset {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            first += top_size + before(low);
+            root = roots + low;
+            depth += top;
+            levels -= top;
+        }
+        return root;
+    }
+
+    //
+    // Interface
+    //
+
+    #[inline]
+    pub fn veb_layout<D: std::clone::Clone>(veb: &mut [D], monotonic: &[D]) -> bool {
+        if veb.len() != monotonic.len() {
+            return false;
+        }
+
+        let n: usize = monotonic.len();
+        for (rank, value) in monotonic.iter().enumerate() {
+            veb[node_index(n, eytzinger_index(n, rank) + 1)] = value.clone();
+        }
+
+        return true;
+    }
+
+    // Rank (index in monotonic array) of element at given index of vEB layout of n elements
+    #[inline]
+    pub fn veb_rank(n: usize, index: usize) -> usize {
+        return eytzinger_rank(n, index_node(n, index) - 1);
+    }
+
+    // Index in vEB layout of n elements for given rank (index in monotonic array)
+    #[inline]
+    pub fn veb_index(n: usize, rank: usize) -> usize {
+        return node_index(n, eytzinger_index(n, rank) + 1);
+    }
+}
+
+use crate::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+
+/// Search of van Emde Boas layout
+pub struct Implementation;
+
+// Index in layout and rank of the first element for which predicate is false, or n if there is
+// none. Nodes are visited from root downwards, so the last probe where predicate was false is at
+// the found position, as ThreeWay expects.
+#[inline]
+fn partition<D, F>(data: &[D], mut pred: F) -> (usize, usize)
+where
+    F: FnMut(&D) -> bool,
+{
+    use crate::binary_search::eytzinger::utility::eytzinger_rank;
+
+    let n: usize = data.len();
+    if n == 0 {
+        return (0, 0);
+    }
+    let height: usize = utility::height(n);
+    let mut ancestors: [usize; 64] = [0; 64];
+    let mut node: usize = 1;
+    let mut depth: usize = 0;
+    while node <= n {
+        let index: usize = if depth == 0 {
+            0
+        } else {
+            utility::locate(n, height, &ancestors, node, depth)
+        };
+        ancestors[depth] = index;
+        node = (node << 1) | (pred(&data[index]) as usize);
+        depth += 1;
+    }
+    // Like Eytzinger search, climb to the last node where predicate was false
+    let shift: usize = node.trailing_ones() as usize + 1;
+    if shift > depth {
+        return (n, n);
+    }
+    node >>= shift;
+    return (ancestors[depth - shift], eytzinger_rank(n, node - 1));
+}
+
+impl<D, I> DynamicBinarySearch<D, I> for Implementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let (index, _): (usize, usize) = partition(data, |element: &D| *element < *value);
+        return if (index < data.len()) && (data[index] == *value) {
+            Some(cast(index).unwrap())
+        } else {
+            None
+        };
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return cast(partition(data, |element: &D| *element < *value).1).unwrap();
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        return cast(partition(data, |element: &D| *element <= *value).1).unwrap();
+    }
+
+    #[inline]
+    fn search(&self, data: &[D], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        use num_traits::cast::cast;
+
+        let (index, rank): (usize, usize) = partition(data, |element: &D| *element < *value);
+        if (index < data.len()) && (data[index] == *value) {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(rank).unwrap());
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        use num_traits::cast::cast;
+
+        return cast(partition(data, pred).1).unwrap();
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        use crate::binary_search::ThreeWay;
+        use num_traits::cast::cast;
+
+        let mut three_way: ThreeWay<F> = ThreeWay::new(f);
+        let (index, rank): (usize, usize) = partition(data, |element: &D| three_way.less(element));
+        if (index < data.len()) && three_way.found() {
+            return Ok(cast(index).unwrap());
+        }
+        return Err(cast(rank).unwrap());
+    }
+
+    #[inline]
+    fn location(&self, data: &[D], position: I) -> I {
+        use num_traits::cast::cast;
+
+        return cast(utility::veb_index(data.len(), cast(position).unwrap())).unwrap();
+    }
+}
+
+impl<D, I, const N: usize> StaticBinarySearch<D, I, N> for Implementation
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+{
+    #[inline]
+    fn r#impl(&self, data: &[D; N], value: &D) -> Option<I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::r#impl(self, data, value);
+    }
+
+    #[inline]
+    fn lower_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::lower_bound(self, data, value);
+    }
+
+    #[inline]
+    fn upper_bound(&self, data: &[D; N], value: &D) -> I
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::upper_bound(self, data, value);
+    }
+
+    #[inline]
+    fn search(&self, data: &[D; N], value: &D) -> Result<I, I>
+    where
+        D: std::cmp::PartialOrd,
+    {
+        return DynamicBinarySearch::search(self, data, value);
+    }
+
+    #[inline]
+    fn partition_point<F>(&self, data: &[D; N], pred: F) -> I
+    where
+        F: FnMut(&D) -> bool,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::partition_point(self, data, pred);
+    }
+
+    #[inline]
+    fn search_by<F>(&self, data: &[D; N], f: F) -> Result<I, I>
+    where
+        F: FnMut(&D) -> std::cmp::Ordering,
+        Self: Sized,
+    {
+        return DynamicBinarySearch::search_by(self, data, f);
+    }
+
+    #[inline]
+    fn location(&self, data: &[D; N], position: I) -> I {
+        return DynamicBinarySearch::location(self, data, position);
+    }
+}
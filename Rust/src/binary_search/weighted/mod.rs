@@ -0,0 +1,123 @@
+// Weighted sampling: item i is picked with probability weight[i] / total. Cumulative sums of
+// weights split [0, total) into ranges [sum before i, sum including i) and the picked item is the
+// first one whose cumulative sum is greater than the threshold, i.e. upper_bound. Ranges of
+// items with zero weight are empty, so they are never picked.
+
+use crate::binary_search::{DynamicBinarySearch, Layout};
+
+/// Why weights were not accepted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeightError {
+    /// There is no item with positive weight
+    NoWeight,
+    /// Weight at index is negative or not a number
+    Invalid(usize),
+    /// Sum of weights up to index does not fit into the weight type
+    Overflow(usize),
+}
+
+impl std::fmt::Display for WeightError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            WeightError::NoWeight => write!(f, "no item has positive weight"),
+            WeightError::Invalid(index) => write!(f, "weight {} is invalid", index),
+            WeightError::Overflow(index) => write!(f, "sum of weights overflows at {}", index),
+        };
+    }
+}
+
+impl std::error::Error for WeightError {}
+
+/// Picks items by weight, searching cumulative weights with implementation B
+pub struct WeightedIndex<W, B> {
+    bs: B,
+    cumulative: Vec<W>,
+    total: W,
+    last: usize,
+}
+
+impl<W, B> WeightedIndex<W, B>
+where
+    W: num_traits::Num
+        + num_traits::Bounded
+        + num_traits::NumCast
+        + std::cmp::PartialOrd
+        + std::marker::Copy,
+    B: Layout,
+{
+    /// Index searched with implementation bs, with cumulative weights arranged in its layout
+    pub fn new(bs: B, weights: &[W]) -> Result<Self, WeightError> {
+        use std::cmp::Ordering;
+
+        let mut cumulative: Vec<W> = Vec::with_capacity(weights.len());
+        let mut total: W = W::zero();
+        let mut last: Option<usize> = None;
+        for (index, weight) in weights.iter().enumerate() {
+            match weight.partial_cmp(&W::zero()) {
+                Some(Ordering::Greater) => last = Some(index),
+                Some(Ordering::Equal) => {}
+                _ => return Err(WeightError::Invalid(index)),
+            }
+            if *weight > W::max_value() - total {
+                return Err(WeightError::Overflow(index));
+            }
+            total = total + *weight;
+            cumulative.push(total);
+        }
+        return match last {
+            Some(last) => Ok(WeightedIndex {
+                cumulative: bs.layout(&cumulative),
+                bs,
+                total,
+                last,
+            }),
+            None => Err(WeightError::NoWeight),
+        };
+    }
+}
+
+impl<W, B> WeightedIndex<W, B>
+where
+    W: num_traits::Num
+        + num_traits::Bounded
+        + num_traits::NumCast
+        + std::cmp::PartialOrd
+        + std::marker::Copy,
+{
+    /// Sum of all weights
+    pub fn total(&self) -> W {
+        return self.total;
+    }
+
+    /// Item whose range of thresholds contains threshold, none if it is not within [0, total)
+    pub fn pick<I>(&self, threshold: &W) -> Option<I>
+    where
+        I: num::Integer + num_traits::NumCast,
+        B: DynamicBinarySearch<W, I>,
+    {
+        if !((W::zero() <= *threshold) && (*threshold < self.total)) {
+            return None;
+        }
+        // Threshold is less than total, i.e. the last cumulative sum, so some item is found. The
+        // position is a rank, so this holds for Eytzinger layout as well
+        return Some(self.bs.upper_bound(&self.cumulative, threshold));
+    }
+
+    /// Item for a uniformly distributed random number within [0, 1), none for other numbers
+    pub fn sample<I>(&self, unit: f64) -> Option<I>
+    where
+        I: num::Integer + num_traits::NumCast,
+        B: DynamicBinarySearch<W, I>,
+    {
+        use num_traits::cast::cast;
+
+        if !(0.0..1.0).contains(&unit) {
+            return None;
+        }
+        let total: f64 = cast(self.total).unwrap();
+        // Rounding may take the threshold to total, which belongs to the last picked item
+        return cast::<f64, W>(unit * total)
+            .and_then(|threshold: W| self.pick(&threshold))
+            .or_else(|| cast(self.last));
+    }
+}
@@ -0,0 +1,82 @@
+// Counting of comparisons, to see how many comparator calls each implementation makes. Searching
+// an array of Counted elements counts every comparison of them made by the current thread.
+
+use std::cell::Cell;
+
+thread_local! {
+    static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[inline]
+fn count() {
+    COMPARISONS.with(|comparisons: &Cell<usize>| comparisons.set(comparisons.get() + 1));
+}
+
+/// Comparisons of Counted elements made by this thread since the last reset
+pub fn comparisons() -> usize {
+    return COMPARISONS.with(|comparisons: &Cell<usize>| comparisons.get());
+}
+
+/// Starts counting from zero
+pub fn reset() {
+    COMPARISONS.with(|comparisons: &Cell<usize>| comparisons.set(0));
+}
+
+/// Element, which counts each comparison made with it as one comparator call
+#[derive(Clone, Copy, Debug)]
+pub struct Counted<D>(pub D);
+
+impl<D> PartialEq for Counted<D>
+where
+    D: std::cmp::Ord,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        count();
+        return self.0 == other.0;
+    }
+}
+
+impl<D> Eq for Counted<D> where D: std::cmp::Ord {}
+
+impl<D> PartialOrd for Counted<D>
+where
+    D: std::cmp::Ord,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<D> Ord for Counted<D>
+where
+    D: std::cmp::Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        count();
+        return self.0.cmp(&other.0);
+    }
+}
+
+// Conversions, e.g. for interpolation, are not comparisons
+impl<D> num_traits::ToPrimitive for Counted<D>
+where
+    D: num_traits::ToPrimitive,
+{
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        return self.0.to_i64();
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        return self.0.to_u64();
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        return self.0.to_f64();
+    }
+}
@@ -1,4 +1,8 @@
-use super::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+pub mod counting;
+
+use super::binary_search::float::{DynamicFloatSearch, NanError, NanPolicy};
+use super::binary_search::{Distance, DynamicBinarySearch, StaticBinarySearch};
+use counting::Counted;
 
 pub struct Dynamic;
 
@@ -79,6 +83,474 @@ impl Dynamic {
         }
         return success;
     }
+
+    pub fn test_bounds<D, I>(
+        bs: &dyn DynamicBinarySearch<D, I>,
+        data: &[D],
+        monotonic: &[D],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: I = cast(monotonic.partition_point(|element: &D| *element < value)).unwrap();
+            let upper: I =
+                cast(monotonic.partition_point(|element: &D| *element <= value)).unwrap();
+            let subsuccess: bool = (bs.lower_bound(data, &value) == lower)
+                && (bs.upper_bound(data, &value) == upper)
+                && (bs.equal_range(data, &value) == (lower..upper));
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_search<D, I>(
+        bs: &dyn DynamicBinarySearch<D, I>,
+        data: &[D],
+        monotonic: &[D],
+    ) -> bool
+    where
+        D: std::cmp::Ord
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let result: Result<I, I> = bs.search(data, &value);
+            let subsuccess: bool = match (result, monotonic.binary_search(&value)) {
+                (Ok(location), Ok(_)) => data[cast::<I, usize>(location).unwrap()] == value,
+                (Err(position), Err(expected)) => position == cast(expected).unwrap(),
+                _ => false,
+            };
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_partition_point<D, I, B>(bs: &B, data: &[D], monotonic: &[D]) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast,
+        B: DynamicBinarySearch<D, I>,
+    {
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            // Doubled elements keep the predicate monotone without being a plain comparison
+            let pred = |element: &D| *element + *element < value;
+            let expected: I = cast(monotonic.partition_point(pred)).unwrap();
+            success = success && (bs.partition_point(data, pred) == expected);
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn duplicate_filler<D>(monotonic: &[D]) -> Vec<D>
+    where
+        D: std::marker::Copy,
+    {
+        // Runs of one to seven copies of each value
+        let mut tmp: Vec<D> = Vec::with_capacity(monotonic.len() * 4);
+        for (i, value) in monotonic.iter().enumerate() {
+            tmp.extend(std::iter::repeat_n(*value, 1 + (i % 7)));
+        }
+        return tmp;
+    }
+
+    pub fn test_duplicates<D, I>(
+        bs: &dyn DynamicBinarySearch<D, I>,
+        data: &[D],
+        monotonic: &[D],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+    {
+        use crate::binary_search::Duplicates;
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: usize = monotonic.partition_point(|element: &D| *element < value);
+            let upper: usize = monotonic.partition_point(|element: &D| *element <= value);
+            let leftmost: Option<I> = bs.find(data, &value, Duplicates::Leftmost);
+            let rightmost: Option<I> = bs.find(data, &value, Duplicates::Rightmost);
+            let any: Option<I> = bs.find(data, &value, Duplicates::Any);
+            let subsuccess: bool = if lower < upper {
+                (leftmost == Some(bs.location(data, cast(lower).unwrap())))
+                    && (rightmost == Some(bs.location(data, cast(upper - 1).unwrap())))
+                    && any.is_some_and(|location: I| {
+                        data[cast::<I, usize>(location).unwrap()] == value
+                    })
+            } else {
+                leftmost.is_none() && rightmost.is_none() && any.is_none()
+            };
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_neighbours<D, I>(
+        bs: &dyn DynamicBinarySearch<D, I>,
+        data: &[D],
+        monotonic: &[D],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + Distance
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+    {
+        use crate::binary_search::TieBreak;
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: usize = monotonic.partition_point(|element: &D| *element < value);
+            let upper: usize = monotonic.partition_point(|element: &D| *element <= value);
+            let floor: Option<I> = if upper > 0 {
+                Some(bs.location(data, cast(upper - 1).unwrap()))
+            } else {
+                None
+            };
+            let ceiling: Option<I> = if lower < monotonic.len() {
+                Some(bs.location(data, cast(lower).unwrap()))
+            } else {
+                None
+            };
+            // Elements are even, so odd values in between are equally close to both neighbours
+            let (nearest_floor, nearest_ceiling): (Option<I>, Option<I>) = match (floor, ceiling) {
+                (Some(below), Some(above)) if below != above => (Some(below), Some(above)),
+                (below, None) => (below, below),
+                (_, above) => (above, above),
+            };
+            let subsuccess: bool = (bs.floor(data, &value) == floor)
+                && (bs.ceiling(data, &value) == ceiling)
+                && (bs.nearest(data, &value, TieBreak::Floor) == nearest_floor)
+                && (bs.nearest(data, &value, TieBreak::Ceiling) == nearest_ceiling);
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    // Reference implementation of range_indices with standard library
+    fn expected_range_indices<D, R>(monotonic: &[D], range: R) -> std::ops::Range<usize>
+    where
+        D: std::cmp::PartialOrd,
+        R: std::ops::RangeBounds<D>,
+    {
+        use std::ops::Bound;
+
+        let start: usize = match range.start_bound() {
+            Bound::Included(low) => monotonic.partition_point(|element: &D| *element < *low),
+            Bound::Excluded(low) => monotonic.partition_point(|element: &D| *element <= *low),
+            Bound::Unbounded => 0,
+        };
+        let end: usize = match range.end_bound() {
+            Bound::Included(high) => monotonic.partition_point(|element: &D| *element <= *high),
+            Bound::Excluded(high) => monotonic.partition_point(|element: &D| *element < *high),
+            Bound::Unbounded => monotonic.len(),
+        };
+        return start..std::cmp::max(start, end);
+    }
+
+    pub fn test_counts<D, I, B>(bs: &B, data: &[D], monotonic: &[D]) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: DynamicBinarySearch<D, I>,
+    {
+        use num_traits::cast::cast;
+        use std::ops::Bound;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let low: D = value;
+            let high: D = value + cast(3).unwrap();
+            let ranges: [(Bound<D>, Bound<D>); 9] = [
+                (Bound::Included(low), Bound::Excluded(high)),
+                (Bound::Included(low), Bound::Included(high)),
+                (Bound::Excluded(low), Bound::Included(high)),
+                (Bound::Excluded(low), Bound::Excluded(high)),
+                (Bound::Unbounded, Bound::Excluded(high)),
+                (Bound::Unbounded, Bound::Included(high)),
+                (Bound::Included(low), Bound::Unbounded),
+                (Bound::Unbounded, Bound::Unbounded),
+                (Bound::Included(high), Bound::Excluded(low)),
+            ];
+            for range in ranges {
+                let expected: std::ops::Range<usize> =
+                    Self::expected_range_indices(monotonic, range);
+                let indices: std::ops::Range<I> = bs.range_indices(data, range);
+                let subsuccess: bool = (indices.start == cast(expected.start).unwrap())
+                    && (indices.end == cast(expected.end).unwrap())
+                    && (bs.count_in_range(data, range) == cast(expected.len()).unwrap());
+                success = success && subsuccess;
+            }
+            let expected: usize = Self::expected_range_indices(monotonic, value..=value).len();
+            success = success && (bs.count(data, &value) == cast(expected).unwrap());
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn float_filler<D>(monotonic: &[D], policy: NanPolicy) -> Vec<D>
+    where
+        D: num_traits::Float + num_traits::float::TotalOrder,
+    {
+        // Special values, NaNs of both signs unless they are rejected
+        let mut tmp: Vec<D> = monotonic.to_vec();
+        tmp.extend([D::neg_infinity(), D::neg_zero(), D::zero(), D::infinity()]);
+        if policy != NanPolicy::Reject {
+            tmp.extend([D::nan(), -D::nan()]);
+        }
+        policy.sort(&mut tmp).unwrap();
+        return tmp;
+    }
+
+    pub fn test_float<D, I, B>(bs: &B, data: &[D], monotonic: &[D], policy: NanPolicy) -> bool
+    where
+        D: num_traits::Float + num_traits::float::TotalOrder,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: DynamicFloatSearch<D, I>,
+    {
+        use num_traits::cast::cast;
+        use std::cmp::Ordering;
+
+        let mut values: Vec<D> = vec![D::nan(), -D::nan(), D::neg_zero(), D::zero()];
+        for value in monotonic {
+            values.extend([*value - D::one(), *value, *value + D::one()]);
+        }
+        let mut success: bool = true;
+        for value in values {
+            if (policy == NanPolicy::Reject) && value.is_nan() {
+                success = success
+                    && (bs.search_float(data, &value, policy) == Err(NanError))
+                    && (bs.lower_bound_float(data, &value, policy) == Err(NanError))
+                    && (bs.upper_bound_float(data, &value, policy) == Err(NanError));
+                continue;
+            }
+            let lower: usize = monotonic
+                .partition_point(|element: &D| policy.compare(element, &value) == Ordering::Less);
+            let upper: usize = monotonic.partition_point(|element: &D| {
+                policy.compare(element, &value) != Ordering::Greater
+            });
+            let subsuccess: bool = match bs.search_float(data, &value, policy) {
+                Ok(Ok(location)) => {
+                    let element: &D = &data[cast::<I, usize>(location).unwrap()];
+                    (lower < upper) && (policy.compare(element, &value) == Ordering::Equal)
+                }
+                Ok(Err(position)) => (lower == upper) && (position == cast(lower).unwrap()),
+                Err(NanError) => false,
+            } && (bs.lower_bound_float(data, &value, policy)
+                == Ok(cast(lower).unwrap()))
+                && (bs.upper_bound_float(data, &value, policy) == Ok(cast(upper).unwrap()));
+            success = success && subsuccess;
+        }
+        return success;
+    }
+
+    pub fn test_descending<D, I, B>(bs: &B, data: &[D], descending: &[D]) -> bool
+    where
+        D: std::cmp::Ord
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + Distance
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: DynamicBinarySearch<D, I>,
+    {
+        use crate::binary_search::{Duplicates, TieBreak};
+        use num_traits::cast::cast;
+
+        let start_value: D = *descending.last().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *descending.first().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: usize = descending.partition_point(|element: &D| *element > value);
+            let upper: usize = descending.partition_point(|element: &D| *element >= value);
+            let found: bool = lower < upper;
+            let search_ok = |result: Result<I, I>| -> bool {
+                return match result {
+                    Ok(location) => found && (data[cast::<I, usize>(location).unwrap()] == value),
+                    Err(position) => !found && (position == cast(lower).unwrap()),
+                };
+            };
+            let bounds: bool = (bs.lower_bound(data, &value) == cast(lower).unwrap())
+                && (bs.upper_bound(data, &value) == cast(upper).unwrap())
+                && (bs.partition_point(data, |element: &D| *element > value)
+                    == cast(lower).unwrap())
+                && (bs.count(data, &value) == cast(upper - lower).unwrap())
+                && (bs.lower_bound_borrowed(data, &value) == cast(lower).unwrap())
+                && (bs.upper_bound_borrowed(data, &value) == cast(upper).unwrap())
+                && search_ok(bs.search(data, &value))
+                && search_ok(bs.search_by_key(data, &value, |element: &D| *element))
+                && search_ok(bs.search_borrowed(data, &value))
+                && search_ok(bs.r#impl(data, &value).ok_or(cast(lower).unwrap()));
+
+            let leftmost: Option<I> = if found {
+                Some(bs.location(data, cast(lower).unwrap()))
+            } else {
+                None
+            };
+            let rightmost: Option<I> = if found {
+                Some(bs.location(data, cast(upper - 1).unwrap()))
+            } else {
+                None
+            };
+            let duplicates: bool = (bs.find(data, &value, Duplicates::Leftmost) == leftmost)
+                && (bs.find(data, &value, Duplicates::Rightmost) == rightmost);
+
+            // Floor is the first element not greater than value, i.e. at lower
+            let floor: Option<I> = if lower < descending.len() {
+                Some(bs.location(data, cast(lower).unwrap()))
+            } else {
+                None
+            };
+            let ceiling: Option<I> = if upper > 0 {
+                Some(bs.location(data, cast(upper - 1).unwrap()))
+            } else {
+                None
+            };
+            // Missing values are odd, so they are equally close to both neighbours
+            let (nearest_floor, nearest_ceiling): (Option<I>, Option<I>) = match (floor, ceiling) {
+                _ if found => (floor, floor),
+                (Some(below), Some(above)) => (Some(below), Some(above)),
+                (below, None) => (below, below),
+                (None, above) => (above, above),
+            };
+            let neighbours: bool = (bs.floor(data, &value) == floor)
+                && (bs.ceiling(data, &value) == ceiling)
+                && (bs.nearest(data, &value, TieBreak::Floor) == nearest_floor)
+                && (bs.nearest(data, &value, TieBreak::Ceiling) == nearest_ceiling);
+
+            // Values of range are in natural order, positions in descending order
+            let high: D = value + cast(3).unwrap();
+            let exclusive: usize = descending.partition_point(|element: &D| *element >= high);
+            let inclusive: usize = descending.partition_point(|element: &D| *element > high);
+            let counts: bool = (bs.range_indices(data, value..high)
+                == (cast(exclusive).unwrap()..cast(upper).unwrap()))
+                && (bs.count_in_range(data, value..=high) == cast(upper - inclusive).unwrap())
+                && (bs.count_in_range(data, high..value) == cast(0).unwrap());
+
+            success = success && bounds && duplicates && neighbours && counts;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_comparisons<D, I, B>(bs: &B, data: &[Counted<D>], monotonic: &[D]) -> bool
+    where
+        D: std::cmp::Ord
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: DynamicBinarySearch<Counted<D>, I>,
+    {
+        use num_traits::cast::cast;
+
+        // One comparison per probe, and probes of any implementation are bounded by this
+        let max_comparisons: usize = match monotonic.len() {
+            0 => 0,
+            n => n.ilog2() as usize + 2,
+        };
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let target: Counted<D> = Counted(value);
+            counting::reset();
+            let result: Result<I, I> = bs.search_cmp(data, &target);
+            let search_comparisons: usize = counting::comparisons();
+            counting::reset();
+            let position: I = bs.lower_bound_by(data, |element: &Counted<D>| element.cmp(&target));
+            let bound_comparisons: usize = counting::comparisons();
+
+            let subsuccess: bool = match (result, monotonic.binary_search(&value)) {
+                (Ok(location), Ok(_)) => data[cast::<I, usize>(location).unwrap()].0 == value,
+                (Err(insert), Err(expected)) => {
+                    (insert == position) && (insert == cast(expected).unwrap())
+                }
+                _ => false,
+            } && (search_comparisons == bound_comparisons)
+                && (search_comparisons <= max_comparisons);
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
 }
 
 pub struct Static;
@@ -157,4 +629,395 @@ impl Static {
         }
         return success;
     }
+
+    pub fn test_bounds<D, I, const N: usize>(
+        bs: &dyn StaticBinarySearch<D, I, N>,
+        data: &[D; N],
+        monotonic: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: I = cast(monotonic.partition_point(|element: &D| *element < value)).unwrap();
+            let upper: I =
+                cast(monotonic.partition_point(|element: &D| *element <= value)).unwrap();
+            let subsuccess: bool = (bs.lower_bound(data, &value) == lower)
+                && (bs.upper_bound(data, &value) == upper)
+                && (bs.equal_range(data, &value) == (lower..upper));
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_search<D, I, const N: usize>(
+        bs: &dyn StaticBinarySearch<D, I, N>,
+        data: &[D; N],
+        monotonic: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::Ord
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast,
+    {
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let result: Result<I, I> = bs.search(data, &value);
+            let subsuccess: bool = match (result, monotonic.binary_search(&value)) {
+                (Ok(location), Ok(_)) => data[cast::<I, usize>(location).unwrap()] == value,
+                (Err(position), Err(expected)) => position == cast(expected).unwrap(),
+                _ => false,
+            };
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_partition_point<D, I, B, const N: usize>(
+        bs: &B,
+        data: &[D; N],
+        monotonic: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast,
+        B: StaticBinarySearch<D, I, N>,
+    {
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            // Doubled elements keep the predicate monotone without being a plain comparison
+            let pred = |element: &D| *element + *element < value;
+            let expected: I = cast(monotonic.partition_point(pred)).unwrap();
+            success = success && (bs.partition_point(data, pred) == expected);
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_duplicates<D, I, const N: usize>(
+        bs: &dyn StaticBinarySearch<D, I, N>,
+        data: &[D; N],
+        monotonic: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+    {
+        use crate::binary_search::Duplicates;
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: usize = monotonic.partition_point(|element: &D| *element < value);
+            let upper: usize = monotonic.partition_point(|element: &D| *element <= value);
+            let leftmost: Option<I> = bs.find(data, &value, Duplicates::Leftmost);
+            let rightmost: Option<I> = bs.find(data, &value, Duplicates::Rightmost);
+            let any: Option<I> = bs.find(data, &value, Duplicates::Any);
+            let subsuccess: bool = if lower < upper {
+                (leftmost == Some(bs.location(data, cast(lower).unwrap())))
+                    && (rightmost == Some(bs.location(data, cast(upper - 1).unwrap())))
+                    && any.is_some_and(|location: I| {
+                        data[cast::<I, usize>(location).unwrap()] == value
+                    })
+            } else {
+                leftmost.is_none() && rightmost.is_none() && any.is_none()
+            };
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_neighbours<D, I, const N: usize>(
+        bs: &dyn StaticBinarySearch<D, I, N>,
+        data: &[D; N],
+        monotonic: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + Distance
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+    {
+        use crate::binary_search::TieBreak;
+        use num_traits::cast::cast;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: usize = monotonic.partition_point(|element: &D| *element < value);
+            let upper: usize = monotonic.partition_point(|element: &D| *element <= value);
+            let floor: Option<I> = if upper > 0 {
+                Some(bs.location(data, cast(upper - 1).unwrap()))
+            } else {
+                None
+            };
+            let ceiling: Option<I> = if lower < monotonic.len() {
+                Some(bs.location(data, cast(lower).unwrap()))
+            } else {
+                None
+            };
+            // Elements are even, so odd values in between are equally close to both neighbours
+            let (nearest_floor, nearest_ceiling): (Option<I>, Option<I>) = match (floor, ceiling) {
+                (Some(below), Some(above)) if below != above => (Some(below), Some(above)),
+                (below, None) => (below, below),
+                (_, above) => (above, above),
+            };
+            let subsuccess: bool = (bs.floor(data, &value) == floor)
+                && (bs.ceiling(data, &value) == ceiling)
+                && (bs.nearest(data, &value, TieBreak::Floor) == nearest_floor)
+                && (bs.nearest(data, &value, TieBreak::Ceiling) == nearest_ceiling);
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_counts<D, I, B, const N: usize>(bs: &B, data: &[D; N], monotonic: &[D; N]) -> bool
+    where
+        D: std::cmp::PartialOrd
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: StaticBinarySearch<D, I, N>,
+    {
+        use num_traits::cast::cast;
+        use std::ops::Bound;
+
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let low: D = value;
+            let high: D = value + cast(3).unwrap();
+            let ranges: [(Bound<D>, Bound<D>); 9] = [
+                (Bound::Included(low), Bound::Excluded(high)),
+                (Bound::Included(low), Bound::Included(high)),
+                (Bound::Excluded(low), Bound::Included(high)),
+                (Bound::Excluded(low), Bound::Excluded(high)),
+                (Bound::Unbounded, Bound::Excluded(high)),
+                (Bound::Unbounded, Bound::Included(high)),
+                (Bound::Included(low), Bound::Unbounded),
+                (Bound::Unbounded, Bound::Unbounded),
+                (Bound::Included(high), Bound::Excluded(low)),
+            ];
+            for range in ranges {
+                let expected: std::ops::Range<usize> =
+                    Dynamic::expected_range_indices(monotonic, range);
+                let indices: std::ops::Range<I> = bs.range_indices(data, range);
+                let subsuccess: bool = (indices.start == cast(expected.start).unwrap())
+                    && (indices.end == cast(expected.end).unwrap())
+                    && (bs.count_in_range(data, range) == cast(expected.len()).unwrap());
+                success = success && subsuccess;
+            }
+            let expected: usize = Dynamic::expected_range_indices(monotonic, value..=value).len();
+            success = success && (bs.count(data, &value) == cast(expected).unwrap());
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_descending<D, I, B, const N: usize>(
+        bs: &B,
+        data: &[D; N],
+        descending: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::Ord
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + Distance
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: StaticBinarySearch<D, I, N>,
+    {
+        use crate::binary_search::{Duplicates, TieBreak};
+        use num_traits::cast::cast;
+
+        let start_value: D = *descending.last().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *descending.first().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let lower: usize = descending.partition_point(|element: &D| *element > value);
+            let upper: usize = descending.partition_point(|element: &D| *element >= value);
+            let found: bool = lower < upper;
+            let search_ok = |result: Result<I, I>| -> bool {
+                return match result {
+                    Ok(location) => found && (data[cast::<I, usize>(location).unwrap()] == value),
+                    Err(position) => !found && (position == cast(lower).unwrap()),
+                };
+            };
+            let bounds: bool = (bs.lower_bound(data, &value) == cast(lower).unwrap())
+                && (bs.upper_bound(data, &value) == cast(upper).unwrap())
+                && (bs.partition_point(data, |element: &D| *element > value)
+                    == cast(lower).unwrap())
+                && (bs.count(data, &value) == cast(upper - lower).unwrap())
+                && (bs.lower_bound_borrowed(data, &value) == cast(lower).unwrap())
+                && (bs.upper_bound_borrowed(data, &value) == cast(upper).unwrap())
+                && search_ok(bs.search(data, &value))
+                && search_ok(bs.search_by_key(data, &value, |element: &D| *element))
+                && search_ok(bs.search_borrowed(data, &value))
+                && search_ok(bs.r#impl(data, &value).ok_or(cast(lower).unwrap()));
+
+            let leftmost: Option<I> = if found {
+                Some(bs.location(data, cast(lower).unwrap()))
+            } else {
+                None
+            };
+            let rightmost: Option<I> = if found {
+                Some(bs.location(data, cast(upper - 1).unwrap()))
+            } else {
+                None
+            };
+            let duplicates: bool = (bs.find(data, &value, Duplicates::Leftmost) == leftmost)
+                && (bs.find(data, &value, Duplicates::Rightmost) == rightmost);
+
+            // Floor is the first element not greater than value, i.e. at lower
+            let floor: Option<I> = if lower < N {
+                Some(bs.location(data, cast(lower).unwrap()))
+            } else {
+                None
+            };
+            let ceiling: Option<I> = if upper > 0 {
+                Some(bs.location(data, cast(upper - 1).unwrap()))
+            } else {
+                None
+            };
+            // Missing values are odd, so they are equally close to both neighbours
+            let (nearest_floor, nearest_ceiling): (Option<I>, Option<I>) = match (floor, ceiling) {
+                _ if found => (floor, floor),
+                (Some(below), Some(above)) => (Some(below), Some(above)),
+                (below, None) => (below, below),
+                (None, above) => (above, above),
+            };
+            let neighbours: bool = (bs.floor(data, &value) == floor)
+                && (bs.ceiling(data, &value) == ceiling)
+                && (bs.nearest(data, &value, TieBreak::Floor) == nearest_floor)
+                && (bs.nearest(data, &value, TieBreak::Ceiling) == nearest_ceiling);
+
+            // Values of range are in natural order, positions in descending order
+            let high: D = value + cast(3).unwrap();
+            let exclusive: usize = descending.partition_point(|element: &D| *element >= high);
+            let inclusive: usize = descending.partition_point(|element: &D| *element > high);
+            let counts: bool = (bs.range_indices(data, value..high)
+                == (cast(exclusive).unwrap()..cast(upper).unwrap()))
+                && (bs.count_in_range(data, value..=high) == cast(upper - inclusive).unwrap())
+                && (bs.count_in_range(data, high..value) == cast(0).unwrap());
+
+            success = success && bounds && duplicates && neighbours && counts;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
+
+    pub fn test_comparisons<D, I, B, const N: usize>(
+        bs: &B,
+        data: &[Counted<D>; N],
+        monotonic: &[D; N],
+    ) -> bool
+    where
+        D: std::cmp::Ord
+            + num_traits::NumCast
+            + std::ops::Sub<Output = D>
+            + std::ops::Add<Output = D>
+            + std::ops::AddAssign
+            + std::marker::Copy,
+        I: std::cmp::PartialEq + num::Integer + num_traits::NumCast + std::marker::Copy,
+        B: StaticBinarySearch<Counted<D>, I, N>,
+    {
+        use num_traits::cast::cast;
+
+        // One comparison per probe, and probes of any implementation are bounded by this
+        let max_comparisons: usize = match N {
+            0 => 0,
+            n => n.ilog2() as usize + 2,
+        };
+        let start_value: D = *monotonic.first().unwrap_or(&cast(0).unwrap()) - cast(1).unwrap();
+        let end_value: D = *monotonic.last().unwrap_or(&cast(0).unwrap()) + cast(1).unwrap();
+        let mut success: bool = true;
+        let mut value: D = start_value;
+        while value <= end_value {
+            let target: Counted<D> = Counted(value);
+            counting::reset();
+            let result: Result<I, I> = bs.search_cmp(data, &target);
+            let search_comparisons: usize = counting::comparisons();
+            counting::reset();
+            let position: I = bs.lower_bound_by(data, |element: &Counted<D>| element.cmp(&target));
+            let bound_comparisons: usize = counting::comparisons();
+
+            let subsuccess: bool = match (result, monotonic.binary_search(&value)) {
+                (Ok(location), Ok(_)) => data[cast::<I, usize>(location).unwrap()].0 == value,
+                (Err(insert), Err(expected)) => {
+                    (insert == position) && (insert == cast(expected).unwrap())
+                }
+                _ => false,
+            } && (search_comparisons == bound_comparisons)
+                && (search_comparisons <= max_comparisons);
+            success = success && subsuccess;
+
+            value += cast(1).unwrap();
+        }
+        return success;
+    }
 }
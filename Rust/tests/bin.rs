@@ -58,6 +58,7 @@ fn binary_search_dynamic_tests_func() -> bool {
             dataset_fixture: DATASET,
         },
         argument: vec![],
+        layout: vec![],
     };
     return tests.run_all_tests();
 }
@@ -1,11 +1,16 @@
 use crate::testing::matcher::assert_true;
 use crate::testing::parameterizedtest::{FrameWorkFixture, FrameWorkTrait};
 use crate::testing::TestResult;
+use bs::binary_search::eytzinger::utility::eytzinger_layout;
+use bs::binary_search::stree::utility::stree_layout;
+use bs::binary_search::veb::utility::veb_layout;
+use bs::helpers::counting::{self, Counted};
 use bs::helpers::Dynamic;
 
 type DataType = i32;
 type SignedIndexType = isize;
 type IndexType = usize;
+type FloatType = f64;
 
 type TestData = usize;
 
@@ -13,11 +18,14 @@ type TestData = usize;
 pub struct BinarySearchDynamicTests {
     pub framework_fixture: FrameWorkFixture<Self, TestData>,
     pub argument: Vec<DataType>,
+    pub layout: Vec<DataType>,
 }
 
 impl FrameWorkTrait<BinarySearchDynamicTests, TestData> for BinarySearchDynamicTests {
     fn setup(&mut self, param: &TestData) {
         self.argument = Dynamic::filler::<DataType, SignedIndexType>(*param);
+        self.layout = self.argument.clone();
+        assert!(eytzinger_layout(&mut self.layout, &self.argument));
     }
 
     fn get_fixture(&self) -> &Self {
@@ -29,7 +37,467 @@ impl FrameWorkTrait<BinarySearchDynamicTests, TestData> for BinarySearchDynamicT
     }
 }
 
-use bs::binary_search::{alternative, power, range, traditional};
+use bs::binary_search::batch::DynamicBatchSearch;
+use bs::binary_search::bisection::{first_true, last_true};
+use bs::binary_search::descending::Descending;
+use bs::binary_search::direct::{Buckets, DirectIndex};
+use bs::binary_search::exponential::{lower_bound_from, search_from, upper_bound_from, Cursor};
+use bs::binary_search::float::NanPolicy;
+use bs::binary_search::interval::{Containment, IntervalTable};
+use bs::binary_search::learned::LearnedIndex;
+use bs::binary_search::prefix::DynamicPrefixSearch;
+use bs::binary_search::weighted::{WeightError, WeightedIndex};
+use bs::binary_search::DynamicBinarySearch;
+use bs::binary_search::{
+    alternative, exponential, eytzinger, interpolation, power, range, simd, stree, traditional,
+    uniform, veb,
+};
+
+// Record sorted by key, intentionally without PartialOrd
+#[derive(Clone, Copy)]
+struct Record {
+    key: DataType,
+    payload: u8,
+}
+
+fn test_search_by<I, B>(bs: &B, data: &[DataType], monotonic: &[DataType]) -> bool
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<Record, I>,
+{
+    use num_traits::cast::cast;
+
+    let records: Vec<Record> = data
+        .iter()
+        .map(|key: &DataType| Record {
+            key: *key,
+            payload: (*key & 0xff) as u8,
+        })
+        .collect();
+    let start_value: DataType = *monotonic.first().unwrap_or(&0) - 1;
+    let end_value: DataType = *monotonic.last().unwrap_or(&0) + 1;
+    let mut success: bool = true;
+    for value in start_value..=end_value {
+        let expected: Result<usize, usize> = monotonic.binary_search(&value);
+        for result in [
+            bs.search_by_key(&records, &value, |record: &Record| record.key),
+            bs.search_by(&records, |record: &Record| record.key.cmp(&value)),
+        ] {
+            let subsuccess: bool = match (result, expected) {
+                (Ok(location), Ok(_)) => {
+                    let record: &Record = &records[cast::<I, usize>(location).unwrap()];
+                    (record.key == value) && (record.payload == (value & 0xff) as u8)
+                }
+                (Err(position), Err(expected)) => position == cast(expected).unwrap(),
+                _ => false,
+            };
+            success = success && subsuccess;
+        }
+    }
+    return success;
+}
+
+const NAN_POLICIES: [NanPolicy; 4] = [
+    NanPolicy::Reject,
+    NanPolicy::First,
+    NanPolicy::Last,
+    NanPolicy::TotalOrder,
+];
+
+// Converts monotonic array into layout searched by an implementation
+type Layout<D> = fn(&mut [D], &[D]) -> bool;
+
+// Numbers of the fixture completed with special values, sorted by each NaN policy, and laid out
+fn test_float<I, B>(bs: &B, argument: &[DataType], layout: Option<Layout<FloatType>>) -> bool
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<FloatType, I>,
+{
+    let numbers: Vec<FloatType> = argument
+        .iter()
+        .map(|value: &DataType| *value as FloatType)
+        .collect();
+    let mut success: bool = true;
+    for policy in NAN_POLICIES {
+        let monotonic: Vec<FloatType> = Dynamic::float_filler(&numbers, policy);
+        let mut data: Vec<FloatType> = monotonic.clone();
+        if let Some(layout) = layout {
+            assert!(layout(&mut data, &monotonic));
+        }
+        success = success && Dynamic::test_float::<FloatType, I, B>(bs, &data, &monotonic, policy);
+    }
+    return success;
+}
+
+// Zero padded, so that strings are in the same order as the numbers
+fn to_string(value: DataType) -> String {
+    return format!("{:07}", value);
+}
+
+// Strings are searched with string slices, without allocating a String per query
+fn test_borrowed<I, B>(bs: &B, data: &[String], monotonic: &[String]) -> bool
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<String, I>,
+{
+    use num_traits::cast::cast;
+
+    let start_value: DataType = monotonic.first().map_or(0, |s: &String| s.parse().unwrap()) - 1;
+    let end_value: DataType = monotonic.last().map_or(0, |s: &String| s.parse().unwrap()) + 1;
+    let mut success: bool = true;
+    for value in start_value..=end_value {
+        let query: String = to_string(value);
+        let key: &str = query.as_str();
+        let lower: usize = monotonic.partition_point(|element: &String| element.as_str() < key);
+        let upper: usize = monotonic.partition_point(|element: &String| element.as_str() <= key);
+        let subsuccess: bool = match bs.search_borrowed(data, key) {
+            Ok(location) => (lower < upper) && (data[cast::<I, usize>(location).unwrap()] == key),
+            Err(position) => (lower == upper) && (position == cast(lower).unwrap()),
+        } && (bs.lower_bound_borrowed(data, key) == cast(lower).unwrap())
+            && (bs.upper_bound_borrowed(data, key) == cast(upper).unwrap());
+        success = success && subsuccess;
+    }
+    return success;
+}
+
+// Prefixes of different lengths of present and missing values, and prefixes longer than elements
+fn test_prefix<D, I, B>(bs: &B, data: &[D], monotonic: &[D]) -> bool
+where
+    D: AsRef<[u8]> + std::cmp::PartialEq,
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<D, I>,
+{
+    use num_traits::cast::cast;
+
+    let mut success: bool =
+        bs.prefix_range(data, "") == (cast(0).unwrap()..cast(data.len()).unwrap());
+    for value in 0..=((monotonic.len() as DataType) << 1) {
+        let text: String = to_string(value);
+        let long: String = text.clone() + "0";
+        for prefix in [&text[..2 + (value as usize % 6)], &text, &long] {
+            let prefix: &[u8] = prefix.as_bytes();
+            let start: usize = monotonic.partition_point(|element: &D| element.as_ref() < prefix);
+            let end: usize = monotonic.partition_point(|element: &D| {
+                (element.as_ref() < prefix) || element.as_ref().starts_with(prefix)
+            });
+            let mut matches = bs.prefix_iter(data, prefix);
+            let subsuccess: bool = (bs.prefix_range(data, prefix)
+                == (cast(start).unwrap()..cast(end).unwrap()))
+                && (matches.len() == end - start)
+                && if end - start <= 16 {
+                    matches.eq(monotonic[start..end].iter())
+                } else {
+                    (matches.next() == Some(&monotonic[start]))
+                        && (matches.next_back() == Some(&monotonic[end - 1]))
+                };
+            success = success && subsuccess;
+        }
+    }
+    return success;
+}
+
+// Interval i is [4i, 4i + i % 3], so that there are gaps of one to three points between intervals
+fn test_intervals<I, B>(table: &IntervalTable<DataType, B>) -> bool
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy + std::fmt::Debug,
+    B: DynamicBinarySearch<DataType, I>,
+{
+    use num_traits::cast::cast;
+
+    let n: DataType = table.len() as DataType;
+    let mut success: bool = true;
+    for point in -2..=(4 * n + 2) {
+        let i: DataType = point.div_euclid(4);
+        let expected: Containment<DataType> = if point < 0 {
+            Containment::Gap(0)
+        } else if i >= n {
+            Containment::Gap(n)
+        } else if point - 4 * i <= i % 3 {
+            Containment::Inside(i)
+        } else {
+            Containment::Gap(i + 1)
+        };
+        let subsuccess: bool = match (table.lookup::<I>(&point), expected) {
+            (Containment::Inside(index), Containment::Inside(i)) => index == cast(i).unwrap(),
+            (Containment::Gap(index), Containment::Gap(i)) => index == cast(i).unwrap(),
+            _ => false,
+        };
+        success = success && subsuccess;
+    }
+    return success;
+}
+
+fn intervals(argument: &[DataType]) -> Vec<(DataType, DataType)> {
+    return (0..argument.len() as DataType)
+        .map(|i: DataType| (4 * i, 4 * i + i % 3))
+        .collect();
+}
+
+// Every fourth item has zero weight, so there is no positive weight with less than two items
+fn weights(argument: &[DataType]) -> Vec<DataType> {
+    return (0..argument.len() as DataType)
+        .map(|i: DataType| i % 4)
+        .collect();
+}
+
+// Compares picks against items expanded by their weights, with thresholds of quarters for floats
+fn test_weighted<I, B>(
+    integer: Result<WeightedIndex<DataType, B>, WeightError>,
+    float: Result<WeightedIndex<FloatType, B>, WeightError>,
+    weights: &[DataType],
+) -> bool
+where
+    I: num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<DataType, I> + DynamicBinarySearch<FloatType, I>,
+{
+    use num_traits::cast::cast;
+
+    let owners: Vec<usize> = weights
+        .iter()
+        .enumerate()
+        .flat_map(|(i, weight): (usize, &DataType)| std::iter::repeat_n(i, *weight as usize))
+        .collect();
+    let (integer, float) = match (integer, float) {
+        (Ok(integer), Ok(float)) => (integer, float),
+        (integer, float) => {
+            return owners.is_empty()
+                && (integer.err() == Some(WeightError::NoWeight))
+                && (float.err() == Some(WeightError::NoWeight));
+        }
+    };
+    let total: DataType = owners.len() as DataType;
+    let mut success: bool = (integer.total() == total) && (float.total() == total as FloatType);
+    for threshold in -1..=total {
+        let expected: Option<I> = owners
+            .get(threshold as usize)
+            .filter(|_| threshold >= 0)
+            .map(|owner: &usize| cast(*owner).unwrap());
+        let subsuccess: bool = (integer.pick::<I>(&threshold) == expected)
+            && (0..4).all(|quarter: DataType| {
+                let threshold: FloatType = threshold as FloatType + quarter as FloatType * 0.25;
+                return float.pick::<I>(&threshold) == expected;
+            });
+        success = success && subsuccess;
+    }
+    let first: I = cast(owners[0]).unwrap();
+    let last: I = cast(owners[owners.len() - 1]).unwrap();
+    return success
+        && (integer.sample::<I>(0.0) == Some(first))
+        && (float.sample::<I>(0.0) == Some(first))
+        && (integer.sample::<I>(1.0 - FloatType::EPSILON) == Some(last))
+        && (float.sample::<I>(1.0 - FloatType::EPSILON) == Some(last));
+}
+
+// Dynamic::test searches values below the first one, which unsigned values do not have
+fn test_unsigned<B>(bs: &B, data: &[u32]) -> bool
+where
+    B: DynamicBinarySearch<u32, IndexType>,
+{
+    let mut success: bool = true;
+    for element in data.iter() {
+        for value in [*element, *element + 1] {
+            success = success && (bs.search(data, &value) == data.binary_search(&value));
+        }
+    }
+    return success;
+}
+
+// Bounds of each value and the values between them, with each instruction set of this CPU
+fn test_levels<D>(data: &[D], between: &[D]) -> bool
+where
+    D: simd::Key,
+{
+    let mut success: bool = true;
+    for level in simd::Level::available() {
+        let bs: simd::Implementation = simd::Implementation::with_level(level).unwrap();
+        for value in data.iter().chain(between.iter()) {
+            let lower: IndexType = bs.lower_bound(data, value);
+            let upper: IndexType = bs.upper_bound(data, value);
+            success = success
+                && (lower == data.partition_point(|element: &D| *element < *value))
+                && (upper == data.partition_point(|element: &D| *element <= *value));
+        }
+    }
+    return success;
+}
+
+// Three probes per guess, and a bounded number of bad guesses before bisection
+fn guarded_probes(n: usize) -> usize {
+    return match n {
+        0 => 0,
+        n => {
+            3 * (interpolation::BAD_GUESSES as usize + n.ilog2() as usize + 1)
+                + n.ilog2() as usize
+                + 2
+        }
+    };
+}
+
+// Lower bounds of each value and the next one do not probe more than given number of elements
+fn test_probes<B>(bs: &B, data: &[Counted<DataType>], max_probes: usize) -> bool
+where
+    B: DynamicBinarySearch<Counted<DataType>, IndexType>,
+{
+    let mut success: bool = true;
+    for element in data.iter() {
+        for value in [element.0, element.0.saturating_add(1)] {
+            let target: Counted<DataType> = Counted(value);
+            counting::reset();
+            let position: IndexType = bs.lower_bound(data, &target);
+            let probes: usize = counting::comparisons();
+            let expected: usize =
+                data.partition_point(|element: &Counted<DataType>| element.0 < value);
+            success = success && (position == expected) && (probes <= max_probes);
+        }
+    }
+    return success;
+}
+
+// Bounds of each value and values between them, with each bucket table configuration; equal
+// values share a bucket, and rounding may add one more. A single bucket holds the whole array.
+fn test_direct<D>(data: &[D], half: D, equal: usize) -> bool
+where
+    D: num_traits::Float,
+{
+    let mut success: bool = true;
+    for buckets in [
+        Buckets::Fixed(1),
+        Buckets::Fixed(data.len() >> 3),
+        Buckets::PerElement(2),
+        Buckets::Gap(usize::MAX),
+    ] {
+        let direct: DirectIndex<D> = DirectIndex::new(data, buckets).unwrap();
+        let gap: bool = match buckets {
+            Buckets::Gap(_) => direct.max_fixup() <= equal + 1,
+            _ => true,
+        };
+        success = success && gap;
+        for element in data.iter() {
+            for value in [*element - half, *element, *element + half] {
+                let lower: usize = data.partition_point(|x: &D| *x < value);
+                let upper: usize = data.partition_point(|x: &D| *x <= value);
+                let expected: Result<usize, usize> = if (lower < upper) && (data[lower] == value) {
+                    Ok(lower)
+                } else {
+                    Err(lower)
+                };
+                success = success
+                    && (direct.lower_bound::<IndexType>(&value) == lower)
+                    && (direct.upper_bound::<IndexType>(&value) == upper)
+                    && (direct.search::<IndexType>(&value) == expected);
+            }
+        }
+    }
+    return success;
+}
+
+// Bounds of each value and values between them, with models of each error, which the error
+// measured does not exceed
+fn test_learned(data: &[DataType]) -> bool {
+    let mut success: bool = true;
+    for error in [0, 1, 4, 64] {
+        let learned: LearnedIndex<DataType, power::DynamicImplementation> =
+            LearnedIndex::new(data, power::DynamicImplementation {}, error).unwrap();
+        success = success && (learned.max_error() <= error);
+        for element in data.iter() {
+            for value in [*element - 1, *element, *element + 1] {
+                let lower: usize = data.partition_point(|x: &DataType| *x < value);
+                let upper: usize = data.partition_point(|x: &DataType| *x <= value);
+                let expected: Result<usize, usize> =
+                    if lower < upper { Ok(lower) } else { Err(lower) };
+                success = success
+                    && (learned.lower_bound::<IndexType>(&value) == lower)
+                    && (learned.upper_bound::<IndexType>(&value) == upper)
+                    && (learned.search::<IndexType>(&value) == expected);
+            }
+        }
+    }
+    return success;
+}
+
+// Each value and values between them, in descending order and more of them than fit into a
+// group, have the results of searching one at a time
+fn test_batch<B>(bs: &B, data: &[DataType], monotonic: &[DataType]) -> bool
+where
+    B: DynamicBatchSearch<DataType, IndexType>,
+{
+    let queries: Vec<DataType> = monotonic
+        .iter()
+        .rev()
+        .flat_map(|value: &DataType| [*value + 1, *value, *value - 1])
+        .collect();
+    let mut results: Vec<Option<IndexType>> = vec![Some(IndexType::MAX); queries.len()];
+    bs.search_batch(data, &queries, &mut results);
+    return queries
+        .iter()
+        .zip(results.iter())
+        .all(|(value, result): (&DataType, &Option<IndexType>)| *result == bs.r#impl(data, value));
+}
+
+// Bounds of each value and values between them, galloped from hints before, at, after and
+// beyond them
+fn test_finger<B>(bs: &B, data: &[DataType]) -> bool
+where
+    B: DynamicBinarySearch<DataType, IndexType>,
+{
+    let mut success: bool = true;
+    for element in data.iter() {
+        for value in [*element - 1, *element, *element + 1] {
+            let lower: usize = data.partition_point(|x: &DataType| *x < value);
+            let upper: usize = data.partition_point(|x: &DataType| *x <= value);
+            let expected: Result<usize, usize> = if lower < upper { Ok(lower) } else { Err(lower) };
+            for hint in [
+                0,
+                lower.saturating_sub(3),
+                lower,
+                upper + 2,
+                data.len() >> 1,
+                usize::MAX,
+            ] {
+                success = success
+                    && (lower_bound_from(bs, data, &value, hint) == lower)
+                    && (upper_bound_from(bs, data, &value, hint) == upper)
+                    && (search_from(bs, data, &value, hint) == expected);
+            }
+        }
+    }
+    return success;
+}
+
+// Streams of each value and values between them, in ascending and descending order; ascending
+// stream of distinct values probes at most twice per query
+fn test_cursor<I, B, N>(new: N, data: &[DataType]) -> bool
+where
+    I: num_traits::Unsigned + num::Integer + num_traits::NumCast + std::marker::Copy,
+    B: DynamicBinarySearch<DataType, I> + DynamicBinarySearch<Counted<DataType>, I>,
+    N: Fn() -> B,
+{
+    use num_traits::cast::cast;
+
+    let values: Vec<DataType> = data
+        .iter()
+        .flat_map(|value: &DataType| [*value - 1, *value, *value + 1])
+        .collect();
+    let mut cursor: Cursor<DataType, I, B> = Cursor::new(data, new());
+    let mut success: bool = true;
+    for value in values.iter().chain(values.iter().rev()) {
+        let lower: usize = data.partition_point(|x: &DataType| *x < *value);
+        let upper: usize = data.partition_point(|x: &DataType| *x <= *value);
+        success = success
+            && (cursor.lower_bound(value) == cast(lower).unwrap())
+            && (cursor.upper_bound(value) == cast(upper).unwrap())
+            && (cursor.search(value).is_ok() == (lower < upper))
+            && (cursor.position() == cast(lower).unwrap());
+    }
+
+    let counted: Vec<Counted<DataType>> = data.iter().map(|v: &DataType| Counted(*v)).collect();
+    let mut cursor: Cursor<Counted<DataType>, I, B> = Cursor::new(&counted, new());
+    counting::reset();
+    for value in counted.iter() {
+        cursor.lower_bound(value);
+    }
+    return success && (counting::comparisons() <= 2 * counted.len());
+}
 
 impl BinarySearchDynamicTests {
     fn signed_traditional(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
@@ -66,32 +534,3107 @@ impl BinarySearchDynamicTests {
         let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument);
         return assert_true(result);
     }
-}
 
-use crate::testing::parameterizedtest::{DataSetFixture, TestCase, TestFixture};
+    fn signed_traditional_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
 
-pub const TESTFIXTURE: TestFixture<BinarySearchDynamicTests, TestData> = TestFixture {
-    name: "BinarySearchDynamicTests",
-    tests: &[
-        TestCase {
-            name: "signed_traditional",
-            test: &BinarySearchDynamicTests::signed_traditional,
-        },
-        TestCase {
-            name: "unsigned_traditional",
-            test: &BinarySearchDynamicTests::unsigned_traditional,
-        },
-        TestCase {
-            name: "alternative",
-            test: &BinarySearchDynamicTests::alternative,
-        },
-        TestCase {
-            name: "range",
-            test: &BinarySearchDynamicTests::range,
-        },
-        TestCase {
-            name: "power",
-            test: &BinarySearchDynamicTests::power,
+        let result: bool = Dynamic::test_bounds::<DataType, SignedIndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn alternative_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn range_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn power_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+
+        let result: bool = Dynamic::test_search::<DataType, SignedIndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn alternative_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn range_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn power_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, SignedIndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn alternative_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn range_partition_point(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn power_partition_point(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn signed_traditional_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+
+        let result: bool =
+            test_search_by::<SignedIndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn alternative_search_by(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn range_search_by(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn power_search_by(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+
+        let result: bool = test_search_by::<IndexType, _>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+
+        let result: bool = test_search_by::<IndexType, _>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+
+        let result: bool = test_search_by::<IndexType, _>(&bs, &fixture.layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, SignedIndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn alternative_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn range_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn power_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, SignedIndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn alternative_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn range_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn power_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn signed_traditional_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, SignedIndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn alternative_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn range_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn power_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool = Dynamic::test_counts::<DataType, IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool = Dynamic::test_counts::<DataType, IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool = Dynamic::test_counts::<DataType, IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_float(
+        fixture: &BinarySearchDynamicTests,
+        param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+
+        let filler: Vec<f32> = Dynamic::filler::<f32, SignedIndexType>(*param);
+
+        let result: bool = Dynamic::test::<f32, SignedIndexType>(&bs, &filler)
+            && test_float::<SignedIndexType, _>(&bs, &fixture.argument, None);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_float(
+        fixture: &BinarySearchDynamicTests,
+        param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+
+        let filler: Vec<f32> = Dynamic::filler::<f32, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<f32, IndexType>(&bs, &filler)
+            && test_float::<IndexType, _>(&bs, &fixture.argument, None);
+        return assert_true(result);
+    }
+
+    fn alternative_float(fixture: &BinarySearchDynamicTests, param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+
+        let filler: Vec<f32> = Dynamic::filler::<f32, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<f32, IndexType>(&bs, &filler)
+            && test_float::<IndexType, _>(&bs, &fixture.argument, None);
+        return assert_true(result);
+    }
+
+    fn range_float(fixture: &BinarySearchDynamicTests, param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+
+        let filler: Vec<f32> = Dynamic::filler::<f32, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<f32, IndexType>(&bs, &filler)
+            && test_float::<IndexType, _>(&bs, &fixture.argument, None);
+        return assert_true(result);
+    }
+
+    fn power_float(fixture: &BinarySearchDynamicTests, param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+
+        let filler: Vec<f32> = Dynamic::filler::<f32, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<f32, IndexType>(&bs, &filler)
+            && test_float::<IndexType, _>(&bs, &fixture.argument, None);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_float(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+
+        let result: bool =
+            test_float::<IndexType, _>(&bs, &fixture.argument, Some(eytzinger_layout));
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_float(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+
+        let result: bool =
+            test_float::<IndexType, _>(&bs, &fixture.argument, Some(eytzinger_layout));
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_float(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+
+        let result: bool =
+            test_float::<IndexType, _>(&bs, &fixture.argument, Some(eytzinger_layout));
+        return assert_true(result);
+    }
+
+    fn signed_traditional_descending(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: Descending<traditional::SignedImplementation> =
+            Descending(traditional::SignedImplementation {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, SignedIndexType, _>(&bs, &descending, &descending);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_descending(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: Descending<traditional::UnsignedImplementation> =
+            Descending(traditional::UnsignedImplementation {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &descending, &descending);
+        return assert_true(result);
+    }
+
+    fn alternative_descending(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: Descending<alternative::Implementation> =
+            Descending(alternative::Implementation {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &descending, &descending);
+        return assert_true(result);
+    }
+
+    fn range_descending(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: Descending<range::Implementation> = Descending(range::Implementation {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &descending, &descending);
+        return assert_true(result);
+    }
+
+    fn power_descending(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: Descending<power::DynamicImplementation> =
+            Descending(power::DynamicImplementation {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &descending, &descending);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_descending(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: Descending<eytzinger::ImplementationWithoutHints> =
+            Descending(eytzinger::ImplementationWithoutHints {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+        let mut layout: Vec<DataType> = descending.clone();
+        assert!(eytzinger_layout(&mut layout, &descending));
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &layout, &descending);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_descending(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: Descending<eytzinger::ImplementationBranchless> =
+            Descending(eytzinger::ImplementationBranchless {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+        let mut layout: Vec<DataType> = descending.clone();
+        assert!(eytzinger_layout(&mut layout, &descending));
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &layout, &descending);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_descending(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: Descending<eytzinger::ImplementationPrefetching> =
+            Descending(eytzinger::ImplementationPrefetching {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+        let mut layout: Vec<DataType> = descending.clone();
+        assert!(eytzinger_layout(&mut layout, &descending));
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &layout, &descending);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_borrowed(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+
+        let result: bool = test_borrowed::<SignedIndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_borrowed(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn alternative_borrowed(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn range_borrowed(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn power_borrowed(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_borrowed(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let mut layout: Vec<String> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_borrowed(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let mut layout: Vec<String> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_borrowed(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let mut layout: Vec<String> = monotonic.clone();
+        assert!(eytzinger_layout(&mut layout, &monotonic));
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_prefix(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+
+        let result: bool = test_prefix::<String, SignedIndexType, _>(&bs, &monotonic, &monotonic)
+            && test_prefix::<Vec<u8>, SignedIndexType, _>(&bs, &monotonic_bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_prefix(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &monotonic, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &monotonic_bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn alternative_prefix(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &monotonic, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &monotonic_bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn range_prefix(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &monotonic, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &monotonic_bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn power_prefix(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &monotonic, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &monotonic_bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_prefix(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+        let mut strings: Vec<String> = monotonic.clone();
+        assert!(eytzinger_layout(&mut strings, &monotonic));
+        let mut bytes: Vec<Vec<u8>> = monotonic_bytes.clone();
+        assert!(eytzinger_layout(&mut bytes, &monotonic_bytes));
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &strings, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_prefix(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+        let mut strings: Vec<String> = monotonic.clone();
+        assert!(eytzinger_layout(&mut strings, &monotonic));
+        let mut bytes: Vec<Vec<u8>> = monotonic_bytes.clone();
+        assert!(eytzinger_layout(&mut bytes, &monotonic_bytes));
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &strings, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_prefix(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+        let mut strings: Vec<String> = monotonic.clone();
+        assert!(eytzinger_layout(&mut strings, &monotonic));
+        let mut bytes: Vec<Vec<u8>> = monotonic_bytes.clone();
+        assert!(eytzinger_layout(&mut bytes, &monotonic_bytes));
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &strings, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_comparisons(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool = Dynamic::test_comparisons::<DataType, SignedIndexType, _>(
+            &bs,
+            &counted,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_comparisons(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn alternative_comparisons(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn range_comparisons(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn power_comparisons(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_comparisons(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .layout
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_comparisons(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .layout
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_comparisons(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+        let counted: Vec<Counted<DataType>> = fixture
+            .layout
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_intervals(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::SignedImplementation = traditional::SignedImplementation {};
+        let table: IntervalTable<DataType, traditional::SignedImplementation> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<SignedIndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_intervals(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: traditional::UnsignedImplementation = traditional::UnsignedImplementation {};
+        let table: IntervalTable<DataType, traditional::UnsignedImplementation> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn alternative_intervals(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: alternative::Implementation = alternative::Implementation {};
+        let table: IntervalTable<DataType, alternative::Implementation> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn range_intervals(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: range::Implementation = range::Implementation {};
+        let table: IntervalTable<DataType, range::Implementation> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn power_intervals(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let table: IntervalTable<DataType, power::DynamicImplementation> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_intervals(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationWithoutHints = eytzinger::ImplementationWithoutHints {};
+        let table: IntervalTable<DataType, eytzinger::ImplementationWithoutHints> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_intervals(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let table: IntervalTable<DataType, eytzinger::ImplementationBranchless> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_intervals(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: eytzinger::ImplementationPrefetching = eytzinger::ImplementationPrefetching {};
+        let table: IntervalTable<DataType, eytzinger::ImplementationPrefetching> =
+            IntervalTable::new(bs, &intervals(&fixture.argument)).unwrap();
+
+        let result: bool = test_intervals::<IndexType, _>(&table);
+        return assert_true(result);
+    }
+
+    fn signed_traditional_weighted(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(traditional::SignedImplementation {}, &weights);
+        let float = WeightedIndex::new(traditional::SignedImplementation {}, &float_weights);
+
+        let result: bool = test_weighted::<SignedIndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn unsigned_traditional_weighted(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(traditional::UnsignedImplementation {}, &weights);
+        let float = WeightedIndex::new(traditional::UnsignedImplementation {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn alternative_weighted(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(alternative::Implementation {}, &weights);
+        let float = WeightedIndex::new(alternative::Implementation {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn range_weighted(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(range::Implementation {}, &weights);
+        let float = WeightedIndex::new(range::Implementation {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn power_weighted(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(power::DynamicImplementation {}, &weights);
+        let float = WeightedIndex::new(power::DynamicImplementation {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn eytzinger_without_hints_weighted(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(eytzinger::ImplementationWithoutHints {}, &weights);
+        let float = WeightedIndex::new(eytzinger::ImplementationWithoutHints {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn eytzinger_branchless_weighted(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(eytzinger::ImplementationBranchless {}, &weights);
+        let float = WeightedIndex::new(eytzinger::ImplementationBranchless {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn eytzinger_prefetching_weighted(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let weights: Vec<DataType> = weights(&fixture.argument);
+        let float_weights: Vec<FloatType> =
+            weights.iter().map(|w: &DataType| *w as FloatType).collect();
+        let integer = WeightedIndex::new(eytzinger::ImplementationPrefetching {}, &weights);
+        let float = WeightedIndex::new(eytzinger::ImplementationPrefetching {}, &float_weights);
+
+        let result: bool = test_weighted::<IndexType, _>(integer, float, &weights);
+        return assert_true(result);
+    }
+
+    fn bisection(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let data: &[DataType] = &fixture.argument;
+        let mut success: bool = true;
+        for value in data.iter() {
+            let found: bool =
+                (first_true(DataType::MIN..=DataType::MAX, |x: DataType| x >= *value)
+                    == Some(*value))
+                    && (last_true(DataType::MIN..=DataType::MAX, |x: DataType| x <= *value)
+                        == Some(*value));
+            let positions: bool = (first_true(0..=data.len(), |i: usize| {
+                (i == data.len()) || (data[i] >= *value)
+            }) == Some(data.partition_point(|x: &DataType| *x < *value)))
+                && (last_true(0..=data.len(), |i: usize| {
+                    (i == 0) || (data[i - 1] <= *value)
+                }) == Some(data.partition_point(|x: &DataType| *x <= *value)));
+            success = success && found && positions;
+        }
+        return assert_true(success);
+    }
+
+    fn exponential_front(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+
+        let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_back(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+
+        let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_front_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_back_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_front_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_back_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_front_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn exponential_back_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn exponential_front_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_back_search_by(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_front_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn exponential_back_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn exponential_front_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn exponential_back_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn exponential_front_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn exponential_back_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: exponential::BackImplementation = exponential::BackImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded(fixture: &BinarySearchDynamicTests, param: &TestData) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+
+        // Both signed and unsigned values
+        let unsigned: Vec<u32> = Dynamic::filler::<u32, IndexType>(*param);
+        let signed: Vec<i64> = Dynamic::filler::<i64, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument)
+            && test_unsigned(&bs, &unsigned)
+            && Dynamic::test::<i64, IndexType>(&bs, &signed);
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential(
+        fixture: &BinarySearchDynamicTests,
+        param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+
+        // Both signed and unsigned values
+        let unsigned: Vec<u32> = Dynamic::filler::<u32, IndexType>(*param);
+        let signed: Vec<i64> = Dynamic::filler::<i64, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument)
+            && test_unsigned(&bs, &unsigned)
+            && Dynamic::test::<i64, IndexType>(&bs, &signed);
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential_bounds(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential_search(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential_duplicates(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential_neighbours(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn interpolation_sequential_counts(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::SequentialImplementation =
+            interpolation::SequentialImplementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn interpolation_guarded_probes(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+        let evenly: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+        // Interpolation guesses too low, when the last value is far away from the others
+        let mut unevenly: Vec<Counted<DataType>> = evenly.clone();
+        if let Some(last) = unevenly.last_mut() {
+            *last = Counted(DataType::MAX);
+        }
+
+        let result: bool = test_probes(&bs, &evenly, 5)
+            && test_probes(&bs, &unevenly, guarded_probes(evenly.len()));
+        return assert_true(result);
+    }
+
+    fn direct(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let doubles: Vec<f64> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| *v as f64)
+            .collect();
+        let floats: Vec<f32> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| *v as f32)
+            .collect();
+        let duplicates: Vec<f64> = Dynamic::duplicate_filler(&fixture.argument)
+            .iter()
+            .map(|v: &DataType| *v as f64)
+            .collect();
+
+        let result: bool = test_direct(&doubles, 0.5, 1)
+            && test_direct(&floats, 0.5, 1)
+            && test_direct(&duplicates, 0.5, 7);
+        return assert_true(result);
+    }
+
+    fn learned(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let duplicates: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool = test_learned(&fixture.argument) && test_learned(&duplicates);
+        return assert_true(result);
+    }
+
+    fn uniform(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+
+        let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn uniform_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn uniform_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn uniform_partition_point(
+        fixture: &BinarySearchDynamicTests,
+        _param: &TestData,
+    ) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn uniform_search_by(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+
+        let result: bool =
+            test_search_by::<IndexType, _>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn uniform_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn uniform_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn uniform_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn uniform_comparisons(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: uniform::DynamicImplementation =
+            uniform::DynamicImplementation::new(fixture.argument.len());
+        let counted: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn simd(fixture: &BinarySearchDynamicTests, param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+
+        // Both signed and unsigned values
+        let unsigned: Vec<u32> = Dynamic::filler::<u32, IndexType>(*param);
+        let signed: Vec<i64> = Dynamic::filler::<i64, IndexType>(*param);
+
+        let result: bool = Dynamic::test::<DataType, IndexType>(&bs, &fixture.argument)
+            && test_unsigned(&bs, &unsigned)
+            && Dynamic::test::<i64, IndexType>(&bs, &signed);
+        return assert_true(result);
+    }
+
+    fn simd_levels(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let between: Vec<DataType> = fixture.argument.iter().map(|v: &DataType| *v + 1).collect();
+        // Unsigned values cross the sign bit, and 64 bit values are negative
+        let offset: u32 = (u32::MAX >> 1) - (fixture.argument.len() as u32);
+        let unsigned = |data: &[DataType]| -> Vec<u32> {
+            return data
+                .iter()
+                .map(|v: &DataType| (*v as u32) + offset)
+                .collect();
+        };
+        let float = |data: &[DataType]| -> Vec<f32> {
+            return data.iter().map(|v: &DataType| *v as f32).collect();
+        };
+        let signed = |data: &[DataType]| -> Vec<i64> {
+            return data
+                .iter()
+                .map(|v: &DataType| (*v as i64) - (1 << 40))
+                .collect();
+        };
+
+        let result: bool = test_levels(&fixture.argument, &between)
+            && test_levels(&unsigned(&fixture.argument), &unsigned(&between))
+            && test_levels(&float(&fixture.argument), &float(&between))
+            && test_levels(&signed(&fixture.argument), &signed(&between));
+        return assert_true(result);
+    }
+
+    fn simd_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn simd_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &fixture.argument, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn simd_partition_point(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn simd_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn simd_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+
+        let result: bool = Dynamic::test_neighbours::<DataType, IndexType>(
+            &bs,
+            &fixture.argument,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn simd_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: simd::Implementation = simd::Implementation::new();
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool =
+            Dynamic::test_counts::<DataType, IndexType, _>(&bs, &monotonic, &monotonic);
+        return assert_true(result);
+    }
+
+    fn stree_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(stree_layout(&mut layout, &fixture.argument));
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn stree_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(stree_layout(&mut layout, &fixture.argument));
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn stree_partition_point(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(stree_layout(&mut layout, &fixture.argument));
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn stree_search_by(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(stree_layout(&mut layout, &fixture.argument));
+
+        let result: bool = test_search_by::<IndexType, _>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn stree_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(stree_layout(&mut layout, &monotonic));
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn stree_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(stree_layout(&mut layout, &fixture.argument));
+
+        let result: bool =
+            Dynamic::test_neighbours::<DataType, IndexType>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn stree_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: stree::Implementation = stree::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(stree_layout(&mut layout, &monotonic));
+
+        let result: bool = Dynamic::test_counts::<DataType, IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn veb_bounds(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(veb_layout(&mut layout, &fixture.argument));
+
+        let result: bool =
+            Dynamic::test_bounds::<DataType, IndexType>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn veb_search(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(veb_layout(&mut layout, &fixture.argument));
+
+        let result: bool =
+            Dynamic::test_search::<DataType, IndexType>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn veb_partition_point(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(veb_layout(&mut layout, &fixture.argument));
+
+        let result: bool = Dynamic::test_partition_point::<DataType, IndexType, _>(
+            &bs,
+            &layout,
+            &fixture.argument,
+        );
+        return assert_true(result);
+    }
+
+    fn veb_search_by(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(veb_layout(&mut layout, &fixture.argument));
+
+        let result: bool = test_search_by::<IndexType, _>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn veb_duplicates(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(veb_layout(&mut layout, &monotonic));
+
+        let result: bool =
+            Dynamic::test_duplicates::<DataType, IndexType>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn veb_neighbours(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let mut layout: Vec<DataType> = fixture.argument.clone();
+        assert!(veb_layout(&mut layout, &fixture.argument));
+
+        let result: bool =
+            Dynamic::test_neighbours::<DataType, IndexType>(&bs, &layout, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn veb_counts(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let monotonic: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = monotonic.clone();
+        assert!(veb_layout(&mut layout, &monotonic));
+
+        let result: bool = Dynamic::test_counts::<DataType, IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn veb_float(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+
+        let result: bool = test_float::<IndexType, _>(&bs, &fixture.argument, Some(veb_layout));
+        return assert_true(result);
+    }
+
+    fn veb_descending(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: Descending<veb::Implementation> = Descending(veb::Implementation {});
+        let mut descending: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        descending.reverse();
+        let mut layout: Vec<DataType> = descending.clone();
+        assert!(veb_layout(&mut layout, &descending));
+
+        let result: bool =
+            Dynamic::test_descending::<DataType, IndexType, _>(&bs, &layout, &descending);
+        return assert_true(result);
+    }
+
+    fn veb_borrowed(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let mut layout: Vec<String> = monotonic.clone();
+        assert!(veb_layout(&mut layout, &monotonic));
+
+        let result: bool = test_borrowed::<IndexType, _>(&bs, &layout, &monotonic);
+        return assert_true(result);
+    }
+
+    fn veb_prefix(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let monotonic: Vec<String> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| to_string(*v))
+            .collect();
+        let monotonic_bytes: Vec<Vec<u8>> = monotonic
+            .iter()
+            .map(|s: &String| s.clone().into_bytes())
+            .collect();
+        let mut strings: Vec<String> = monotonic.clone();
+        assert!(veb_layout(&mut strings, &monotonic));
+        let mut bytes: Vec<Vec<u8>> = monotonic_bytes.clone();
+        assert!(veb_layout(&mut bytes, &monotonic_bytes));
+
+        let result: bool = test_prefix::<String, IndexType, _>(&bs, &strings, &monotonic)
+            && test_prefix::<Vec<u8>, IndexType, _>(&bs, &bytes, &monotonic_bytes);
+        return assert_true(result);
+    }
+
+    fn veb_comparisons(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: veb::Implementation = veb::Implementation {};
+        let monotonic: Vec<Counted<DataType>> = fixture
+            .argument
+            .iter()
+            .map(|v: &DataType| Counted(*v))
+            .collect();
+        let mut counted: Vec<Counted<DataType>> = monotonic.clone();
+        assert!(veb_layout(&mut counted, &monotonic));
+
+        let result: bool =
+            Dynamic::test_comparisons::<DataType, IndexType, _>(&bs, &counted, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn power_batch(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        let duplicates: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool = test_batch(&bs, &fixture.argument, &fixture.argument)
+            && test_batch(&bs, &duplicates, &duplicates);
+        return assert_true(result);
+    }
+
+    fn eytzinger_batch(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let duplicates: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+        let mut layout: Vec<DataType> = duplicates.clone();
+        assert!(eytzinger_layout(&mut layout, &duplicates));
+
+        let result: bool = test_batch(
+            &eytzinger::ImplementationWithoutHints {},
+            &fixture.layout,
+            &fixture.argument,
+        ) && test_batch(
+            &eytzinger::ImplementationBranchless {},
+            &fixture.layout,
+            &fixture.argument,
+        ) && test_batch(
+            &eytzinger::ImplementationPrefetching {},
+            &fixture.layout,
+            &fixture.argument,
+        ) && test_batch(
+            &eytzinger::ImplementationBranchless {},
+            &layout,
+            &duplicates,
+        );
+        return assert_true(result);
+    }
+
+    fn exponential_finger(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let duplicates: Vec<DataType> = Dynamic::duplicate_filler(&fixture.argument);
+
+        let result: bool = test_finger(&exponential::FrontImplementation {}, &fixture.argument)
+            && test_finger(&exponential::BackImplementation {}, &duplicates)
+            && test_finger(&power::DynamicImplementation {}, &duplicates)
+            && test_finger(&range::Implementation {}, &fixture.argument);
+        return assert_true(result);
+    }
+
+    fn exponential_cursor(fixture: &BinarySearchDynamicTests, _param: &TestData) -> TestResult {
+        let length: usize = fixture.argument.len();
+
+        let result: bool = ((length > u8::MAX as usize)
+            || test_cursor::<u8, _, _>(|| exponential::FrontImplementation {}, &fixture.argument))
+            && ((length > u16::MAX as usize)
+                || test_cursor::<u16, _, _>(|| power::DynamicImplementation {}, &fixture.argument))
+            && test_cursor::<IndexType, _, _>(
+                || exponential::FrontImplementation {},
+                &fixture.argument,
+            )
+            && test_cursor::<IndexType, _, _>(
+                || power::DynamicImplementation {},
+                &fixture.argument,
+            );
+        return assert_true(result);
+    }
+}
+
+use crate::testing::parameterizedtest::{DataSetFixture, TestCase, TestFixture};
+
+pub const TESTFIXTURE: TestFixture<BinarySearchDynamicTests, TestData> = TestFixture {
+    name: "BinarySearchDynamicTests",
+    tests: &[
+        TestCase {
+            name: "signed_traditional",
+            test: &BinarySearchDynamicTests::signed_traditional,
+        },
+        TestCase {
+            name: "unsigned_traditional",
+            test: &BinarySearchDynamicTests::unsigned_traditional,
+        },
+        TestCase {
+            name: "alternative",
+            test: &BinarySearchDynamicTests::alternative,
+        },
+        TestCase {
+            name: "range",
+            test: &BinarySearchDynamicTests::range,
+        },
+        TestCase {
+            name: "power",
+            test: &BinarySearchDynamicTests::power,
+        },
+        TestCase {
+            name: "signed_traditional_bounds",
+            test: &BinarySearchDynamicTests::signed_traditional_bounds,
+        },
+        TestCase {
+            name: "unsigned_traditional_bounds",
+            test: &BinarySearchDynamicTests::unsigned_traditional_bounds,
+        },
+        TestCase {
+            name: "alternative_bounds",
+            test: &BinarySearchDynamicTests::alternative_bounds,
+        },
+        TestCase {
+            name: "range_bounds",
+            test: &BinarySearchDynamicTests::range_bounds,
+        },
+        TestCase {
+            name: "power_bounds",
+            test: &BinarySearchDynamicTests::power_bounds,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_bounds",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_bounds,
+        },
+        TestCase {
+            name: "eytzinger_branchless_bounds",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_bounds,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_bounds",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_bounds,
+        },
+        TestCase {
+            name: "signed_traditional_search",
+            test: &BinarySearchDynamicTests::signed_traditional_search,
+        },
+        TestCase {
+            name: "unsigned_traditional_search",
+            test: &BinarySearchDynamicTests::unsigned_traditional_search,
+        },
+        TestCase {
+            name: "alternative_search",
+            test: &BinarySearchDynamicTests::alternative_search,
+        },
+        TestCase {
+            name: "range_search",
+            test: &BinarySearchDynamicTests::range_search,
+        },
+        TestCase {
+            name: "power_search",
+            test: &BinarySearchDynamicTests::power_search,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_search",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_search,
+        },
+        TestCase {
+            name: "eytzinger_branchless_search",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_search,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_search",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_search,
+        },
+        TestCase {
+            name: "signed_traditional_partition_point",
+            test: &BinarySearchDynamicTests::signed_traditional_partition_point,
+        },
+        TestCase {
+            name: "unsigned_traditional_partition_point",
+            test: &BinarySearchDynamicTests::unsigned_traditional_partition_point,
+        },
+        TestCase {
+            name: "alternative_partition_point",
+            test: &BinarySearchDynamicTests::alternative_partition_point,
+        },
+        TestCase {
+            name: "range_partition_point",
+            test: &BinarySearchDynamicTests::range_partition_point,
+        },
+        TestCase {
+            name: "power_partition_point",
+            test: &BinarySearchDynamicTests::power_partition_point,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_partition_point",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_partition_point,
+        },
+        TestCase {
+            name: "eytzinger_branchless_partition_point",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_partition_point,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_partition_point",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_partition_point,
+        },
+        TestCase {
+            name: "signed_traditional_search_by",
+            test: &BinarySearchDynamicTests::signed_traditional_search_by,
+        },
+        TestCase {
+            name: "unsigned_traditional_search_by",
+            test: &BinarySearchDynamicTests::unsigned_traditional_search_by,
+        },
+        TestCase {
+            name: "alternative_search_by",
+            test: &BinarySearchDynamicTests::alternative_search_by,
+        },
+        TestCase {
+            name: "range_search_by",
+            test: &BinarySearchDynamicTests::range_search_by,
+        },
+        TestCase {
+            name: "power_search_by",
+            test: &BinarySearchDynamicTests::power_search_by,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_search_by",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_search_by,
+        },
+        TestCase {
+            name: "eytzinger_branchless_search_by",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_search_by,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_search_by",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_search_by,
+        },
+        TestCase {
+            name: "signed_traditional_duplicates",
+            test: &BinarySearchDynamicTests::signed_traditional_duplicates,
+        },
+        TestCase {
+            name: "unsigned_traditional_duplicates",
+            test: &BinarySearchDynamicTests::unsigned_traditional_duplicates,
+        },
+        TestCase {
+            name: "alternative_duplicates",
+            test: &BinarySearchDynamicTests::alternative_duplicates,
+        },
+        TestCase {
+            name: "range_duplicates",
+            test: &BinarySearchDynamicTests::range_duplicates,
+        },
+        TestCase {
+            name: "power_duplicates",
+            test: &BinarySearchDynamicTests::power_duplicates,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_duplicates",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_duplicates,
+        },
+        TestCase {
+            name: "eytzinger_branchless_duplicates",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_duplicates,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_duplicates",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_duplicates,
+        },
+        TestCase {
+            name: "signed_traditional_neighbours",
+            test: &BinarySearchDynamicTests::signed_traditional_neighbours,
+        },
+        TestCase {
+            name: "unsigned_traditional_neighbours",
+            test: &BinarySearchDynamicTests::unsigned_traditional_neighbours,
+        },
+        TestCase {
+            name: "alternative_neighbours",
+            test: &BinarySearchDynamicTests::alternative_neighbours,
+        },
+        TestCase {
+            name: "range_neighbours",
+            test: &BinarySearchDynamicTests::range_neighbours,
+        },
+        TestCase {
+            name: "power_neighbours",
+            test: &BinarySearchDynamicTests::power_neighbours,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_neighbours",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_neighbours,
+        },
+        TestCase {
+            name: "eytzinger_branchless_neighbours",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_neighbours,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_neighbours",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_neighbours,
+        },
+        TestCase {
+            name: "signed_traditional_counts",
+            test: &BinarySearchDynamicTests::signed_traditional_counts,
+        },
+        TestCase {
+            name: "unsigned_traditional_counts",
+            test: &BinarySearchDynamicTests::unsigned_traditional_counts,
+        },
+        TestCase {
+            name: "alternative_counts",
+            test: &BinarySearchDynamicTests::alternative_counts,
+        },
+        TestCase {
+            name: "range_counts",
+            test: &BinarySearchDynamicTests::range_counts,
+        },
+        TestCase {
+            name: "power_counts",
+            test: &BinarySearchDynamicTests::power_counts,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_counts",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_counts,
+        },
+        TestCase {
+            name: "eytzinger_branchless_counts",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_counts,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_counts",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_counts,
+        },
+        TestCase {
+            name: "signed_traditional_float",
+            test: &BinarySearchDynamicTests::signed_traditional_float,
+        },
+        TestCase {
+            name: "unsigned_traditional_float",
+            test: &BinarySearchDynamicTests::unsigned_traditional_float,
+        },
+        TestCase {
+            name: "alternative_float",
+            test: &BinarySearchDynamicTests::alternative_float,
+        },
+        TestCase {
+            name: "range_float",
+            test: &BinarySearchDynamicTests::range_float,
+        },
+        TestCase {
+            name: "power_float",
+            test: &BinarySearchDynamicTests::power_float,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_float",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_float,
+        },
+        TestCase {
+            name: "eytzinger_branchless_float",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_float,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_float",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_float,
+        },
+        TestCase {
+            name: "signed_traditional_descending",
+            test: &BinarySearchDynamicTests::signed_traditional_descending,
+        },
+        TestCase {
+            name: "unsigned_traditional_descending",
+            test: &BinarySearchDynamicTests::unsigned_traditional_descending,
+        },
+        TestCase {
+            name: "alternative_descending",
+            test: &BinarySearchDynamicTests::alternative_descending,
+        },
+        TestCase {
+            name: "range_descending",
+            test: &BinarySearchDynamicTests::range_descending,
+        },
+        TestCase {
+            name: "power_descending",
+            test: &BinarySearchDynamicTests::power_descending,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_descending",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_descending,
+        },
+        TestCase {
+            name: "eytzinger_branchless_descending",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_descending,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_descending",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_descending,
+        },
+        TestCase {
+            name: "signed_traditional_borrowed",
+            test: &BinarySearchDynamicTests::signed_traditional_borrowed,
+        },
+        TestCase {
+            name: "unsigned_traditional_borrowed",
+            test: &BinarySearchDynamicTests::unsigned_traditional_borrowed,
+        },
+        TestCase {
+            name: "alternative_borrowed",
+            test: &BinarySearchDynamicTests::alternative_borrowed,
+        },
+        TestCase {
+            name: "range_borrowed",
+            test: &BinarySearchDynamicTests::range_borrowed,
+        },
+        TestCase {
+            name: "power_borrowed",
+            test: &BinarySearchDynamicTests::power_borrowed,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_borrowed",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_borrowed,
+        },
+        TestCase {
+            name: "eytzinger_branchless_borrowed",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_borrowed,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_borrowed",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_borrowed,
+        },
+        TestCase {
+            name: "signed_traditional_prefix",
+            test: &BinarySearchDynamicTests::signed_traditional_prefix,
+        },
+        TestCase {
+            name: "unsigned_traditional_prefix",
+            test: &BinarySearchDynamicTests::unsigned_traditional_prefix,
+        },
+        TestCase {
+            name: "alternative_prefix",
+            test: &BinarySearchDynamicTests::alternative_prefix,
+        },
+        TestCase {
+            name: "range_prefix",
+            test: &BinarySearchDynamicTests::range_prefix,
+        },
+        TestCase {
+            name: "power_prefix",
+            test: &BinarySearchDynamicTests::power_prefix,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_prefix",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_prefix,
+        },
+        TestCase {
+            name: "eytzinger_branchless_prefix",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_prefix,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_prefix",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_prefix,
+        },
+        TestCase {
+            name: "signed_traditional_comparisons",
+            test: &BinarySearchDynamicTests::signed_traditional_comparisons,
+        },
+        TestCase {
+            name: "unsigned_traditional_comparisons",
+            test: &BinarySearchDynamicTests::unsigned_traditional_comparisons,
+        },
+        TestCase {
+            name: "alternative_comparisons",
+            test: &BinarySearchDynamicTests::alternative_comparisons,
+        },
+        TestCase {
+            name: "range_comparisons",
+            test: &BinarySearchDynamicTests::range_comparisons,
+        },
+        TestCase {
+            name: "power_comparisons",
+            test: &BinarySearchDynamicTests::power_comparisons,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_comparisons",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_comparisons,
+        },
+        TestCase {
+            name: "eytzinger_branchless_comparisons",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_comparisons,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_comparisons",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_comparisons,
+        },
+        TestCase {
+            name: "signed_traditional_intervals",
+            test: &BinarySearchDynamicTests::signed_traditional_intervals,
+        },
+        TestCase {
+            name: "unsigned_traditional_intervals",
+            test: &BinarySearchDynamicTests::unsigned_traditional_intervals,
+        },
+        TestCase {
+            name: "alternative_intervals",
+            test: &BinarySearchDynamicTests::alternative_intervals,
+        },
+        TestCase {
+            name: "range_intervals",
+            test: &BinarySearchDynamicTests::range_intervals,
+        },
+        TestCase {
+            name: "power_intervals",
+            test: &BinarySearchDynamicTests::power_intervals,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_intervals",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_intervals,
+        },
+        TestCase {
+            name: "eytzinger_branchless_intervals",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_intervals,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_intervals",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_intervals,
+        },
+        TestCase {
+            name: "signed_traditional_weighted",
+            test: &BinarySearchDynamicTests::signed_traditional_weighted,
+        },
+        TestCase {
+            name: "unsigned_traditional_weighted",
+            test: &BinarySearchDynamicTests::unsigned_traditional_weighted,
+        },
+        TestCase {
+            name: "alternative_weighted",
+            test: &BinarySearchDynamicTests::alternative_weighted,
+        },
+        TestCase {
+            name: "range_weighted",
+            test: &BinarySearchDynamicTests::range_weighted,
+        },
+        TestCase {
+            name: "power_weighted",
+            test: &BinarySearchDynamicTests::power_weighted,
+        },
+        TestCase {
+            name: "eytzinger_without_hints_weighted",
+            test: &BinarySearchDynamicTests::eytzinger_without_hints_weighted,
+        },
+        TestCase {
+            name: "eytzinger_branchless_weighted",
+            test: &BinarySearchDynamicTests::eytzinger_branchless_weighted,
+        },
+        TestCase {
+            name: "eytzinger_prefetching_weighted",
+            test: &BinarySearchDynamicTests::eytzinger_prefetching_weighted,
+        },
+        TestCase {
+            name: "bisection",
+            test: &BinarySearchDynamicTests::bisection,
+        },
+        TestCase {
+            name: "exponential_front",
+            test: &BinarySearchDynamicTests::exponential_front,
+        },
+        TestCase {
+            name: "exponential_back",
+            test: &BinarySearchDynamicTests::exponential_back,
+        },
+        TestCase {
+            name: "exponential_front_bounds",
+            test: &BinarySearchDynamicTests::exponential_front_bounds,
+        },
+        TestCase {
+            name: "exponential_back_bounds",
+            test: &BinarySearchDynamicTests::exponential_back_bounds,
+        },
+        TestCase {
+            name: "exponential_front_search",
+            test: &BinarySearchDynamicTests::exponential_front_search,
+        },
+        TestCase {
+            name: "exponential_back_search",
+            test: &BinarySearchDynamicTests::exponential_back_search,
+        },
+        TestCase {
+            name: "exponential_front_partition_point",
+            test: &BinarySearchDynamicTests::exponential_front_partition_point,
+        },
+        TestCase {
+            name: "exponential_back_partition_point",
+            test: &BinarySearchDynamicTests::exponential_back_partition_point,
+        },
+        TestCase {
+            name: "exponential_front_search_by",
+            test: &BinarySearchDynamicTests::exponential_front_search_by,
+        },
+        TestCase {
+            name: "exponential_back_search_by",
+            test: &BinarySearchDynamicTests::exponential_back_search_by,
+        },
+        TestCase {
+            name: "exponential_front_duplicates",
+            test: &BinarySearchDynamicTests::exponential_front_duplicates,
+        },
+        TestCase {
+            name: "exponential_back_duplicates",
+            test: &BinarySearchDynamicTests::exponential_back_duplicates,
+        },
+        TestCase {
+            name: "exponential_front_neighbours",
+            test: &BinarySearchDynamicTests::exponential_front_neighbours,
+        },
+        TestCase {
+            name: "exponential_back_neighbours",
+            test: &BinarySearchDynamicTests::exponential_back_neighbours,
+        },
+        TestCase {
+            name: "exponential_front_counts",
+            test: &BinarySearchDynamicTests::exponential_front_counts,
+        },
+        TestCase {
+            name: "exponential_back_counts",
+            test: &BinarySearchDynamicTests::exponential_back_counts,
+        },
+        TestCase {
+            name: "interpolation_guarded",
+            test: &BinarySearchDynamicTests::interpolation_guarded,
+        },
+        TestCase {
+            name: "interpolation_sequential",
+            test: &BinarySearchDynamicTests::interpolation_sequential,
+        },
+        TestCase {
+            name: "interpolation_guarded_bounds",
+            test: &BinarySearchDynamicTests::interpolation_guarded_bounds,
+        },
+        TestCase {
+            name: "interpolation_sequential_bounds",
+            test: &BinarySearchDynamicTests::interpolation_sequential_bounds,
+        },
+        TestCase {
+            name: "interpolation_guarded_search",
+            test: &BinarySearchDynamicTests::interpolation_guarded_search,
+        },
+        TestCase {
+            name: "interpolation_sequential_search",
+            test: &BinarySearchDynamicTests::interpolation_sequential_search,
+        },
+        TestCase {
+            name: "interpolation_guarded_partition_point",
+            test: &BinarySearchDynamicTests::interpolation_guarded_partition_point,
+        },
+        TestCase {
+            name: "interpolation_sequential_partition_point",
+            test: &BinarySearchDynamicTests::interpolation_sequential_partition_point,
+        },
+        TestCase {
+            name: "interpolation_guarded_duplicates",
+            test: &BinarySearchDynamicTests::interpolation_guarded_duplicates,
+        },
+        TestCase {
+            name: "interpolation_sequential_duplicates",
+            test: &BinarySearchDynamicTests::interpolation_sequential_duplicates,
+        },
+        TestCase {
+            name: "interpolation_guarded_neighbours",
+            test: &BinarySearchDynamicTests::interpolation_guarded_neighbours,
+        },
+        TestCase {
+            name: "interpolation_sequential_neighbours",
+            test: &BinarySearchDynamicTests::interpolation_sequential_neighbours,
+        },
+        TestCase {
+            name: "interpolation_guarded_counts",
+            test: &BinarySearchDynamicTests::interpolation_guarded_counts,
+        },
+        TestCase {
+            name: "interpolation_sequential_counts",
+            test: &BinarySearchDynamicTests::interpolation_sequential_counts,
+        },
+        TestCase {
+            name: "interpolation_guarded_probes",
+            test: &BinarySearchDynamicTests::interpolation_guarded_probes,
+        },
+        TestCase {
+            name: "direct",
+            test: &BinarySearchDynamicTests::direct,
+        },
+        TestCase {
+            name: "learned",
+            test: &BinarySearchDynamicTests::learned,
+        },
+        TestCase {
+            name: "uniform",
+            test: &BinarySearchDynamicTests::uniform,
+        },
+        TestCase {
+            name: "uniform_bounds",
+            test: &BinarySearchDynamicTests::uniform_bounds,
+        },
+        TestCase {
+            name: "uniform_search",
+            test: &BinarySearchDynamicTests::uniform_search,
+        },
+        TestCase {
+            name: "uniform_partition_point",
+            test: &BinarySearchDynamicTests::uniform_partition_point,
+        },
+        TestCase {
+            name: "uniform_search_by",
+            test: &BinarySearchDynamicTests::uniform_search_by,
+        },
+        TestCase {
+            name: "uniform_duplicates",
+            test: &BinarySearchDynamicTests::uniform_duplicates,
+        },
+        TestCase {
+            name: "uniform_neighbours",
+            test: &BinarySearchDynamicTests::uniform_neighbours,
+        },
+        TestCase {
+            name: "uniform_counts",
+            test: &BinarySearchDynamicTests::uniform_counts,
+        },
+        TestCase {
+            name: "uniform_comparisons",
+            test: &BinarySearchDynamicTests::uniform_comparisons,
+        },
+        TestCase {
+            name: "simd",
+            test: &BinarySearchDynamicTests::simd,
+        },
+        TestCase {
+            name: "simd_levels",
+            test: &BinarySearchDynamicTests::simd_levels,
+        },
+        TestCase {
+            name: "simd_bounds",
+            test: &BinarySearchDynamicTests::simd_bounds,
+        },
+        TestCase {
+            name: "simd_search",
+            test: &BinarySearchDynamicTests::simd_search,
+        },
+        TestCase {
+            name: "simd_partition_point",
+            test: &BinarySearchDynamicTests::simd_partition_point,
+        },
+        TestCase {
+            name: "simd_duplicates",
+            test: &BinarySearchDynamicTests::simd_duplicates,
+        },
+        TestCase {
+            name: "simd_neighbours",
+            test: &BinarySearchDynamicTests::simd_neighbours,
+        },
+        TestCase {
+            name: "simd_counts",
+            test: &BinarySearchDynamicTests::simd_counts,
+        },
+        TestCase {
+            name: "stree_bounds",
+            test: &BinarySearchDynamicTests::stree_bounds,
+        },
+        TestCase {
+            name: "stree_search",
+            test: &BinarySearchDynamicTests::stree_search,
+        },
+        TestCase {
+            name: "stree_partition_point",
+            test: &BinarySearchDynamicTests::stree_partition_point,
+        },
+        TestCase {
+            name: "stree_search_by",
+            test: &BinarySearchDynamicTests::stree_search_by,
+        },
+        TestCase {
+            name: "stree_duplicates",
+            test: &BinarySearchDynamicTests::stree_duplicates,
+        },
+        TestCase {
+            name: "stree_neighbours",
+            test: &BinarySearchDynamicTests::stree_neighbours,
+        },
+        TestCase {
+            name: "stree_counts",
+            test: &BinarySearchDynamicTests::stree_counts,
+        },
+        TestCase {
+            name: "veb_bounds",
+            test: &BinarySearchDynamicTests::veb_bounds,
+        },
+        TestCase {
+            name: "veb_search",
+            test: &BinarySearchDynamicTests::veb_search,
+        },
+        TestCase {
+            name: "veb_partition_point",
+            test: &BinarySearchDynamicTests::veb_partition_point,
+        },
+        TestCase {
+            name: "veb_search_by",
+            test: &BinarySearchDynamicTests::veb_search_by,
+        },
+        TestCase {
+            name: "veb_duplicates",
+            test: &BinarySearchDynamicTests::veb_duplicates,
+        },
+        TestCase {
+            name: "veb_neighbours",
+            test: &BinarySearchDynamicTests::veb_neighbours,
+        },
+        TestCase {
+            name: "veb_counts",
+            test: &BinarySearchDynamicTests::veb_counts,
+        },
+        TestCase {
+            name: "veb_float",
+            test: &BinarySearchDynamicTests::veb_float,
+        },
+        TestCase {
+            name: "veb_descending",
+            test: &BinarySearchDynamicTests::veb_descending,
+        },
+        TestCase {
+            name: "veb_borrowed",
+            test: &BinarySearchDynamicTests::veb_borrowed,
+        },
+        TestCase {
+            name: "veb_prefix",
+            test: &BinarySearchDynamicTests::veb_prefix,
+        },
+        TestCase {
+            name: "veb_comparisons",
+            test: &BinarySearchDynamicTests::veb_comparisons,
+        },
+        TestCase {
+            name: "power_batch",
+            test: &BinarySearchDynamicTests::power_batch,
+        },
+        TestCase {
+            name: "eytzinger_batch",
+            test: &BinarySearchDynamicTests::eytzinger_batch,
+        },
+        TestCase {
+            name: "exponential_finger",
+            test: &BinarySearchDynamicTests::exponential_finger,
+        },
+        TestCase {
+            name: "exponential_cursor",
+            test: &BinarySearchDynamicTests::exponential_cursor,
         },
     ],
 };
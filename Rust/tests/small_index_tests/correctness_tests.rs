@@ -58,6 +58,31 @@ impl CorrectnessTests {
         let result: bool = Static::test::<DataType, SmallIndexType, SIZE>(&bs, &argument);
         return assert_true(result);
     }
+
+    // The largest length that fits the index type, so that every position does
+    fn power_bounds(_fixture: &CorrectnessTests) -> TestResult {
+        use bs::binary_search::{DynamicBinarySearch, StaticBinarySearch};
+
+        const SIZE: usize = SmallIndexType::MAX as usize;
+        let argument: [DataType; SIZE] = Static::filler::<DataType, SmallIndexType, SIZE>();
+        let past: DataType = argument[SIZE - 1] + 1;
+        let dynamic: power::DynamicImplementation = power::DynamicImplementation {};
+        let fixed: power::StaticImplementation = power::StaticImplementation {};
+
+        let result: bool = (DynamicBinarySearch::<DataType, SmallIndexType>::lower_bound(
+            &dynamic, &argument, &past,
+        ) == SmallIndexType::MAX)
+            && (DynamicBinarySearch::<DataType, SmallIndexType>::search(
+                &dynamic, &argument, &past,
+            ) == Err(SmallIndexType::MAX))
+            && (StaticBinarySearch::<DataType, SmallIndexType, SIZE>::upper_bound(
+                &fixed, &argument, &past,
+            ) == SmallIndexType::MAX)
+            && (StaticBinarySearch::<DataType, SmallIndexType, SIZE>::search(
+                &fixed, &argument, &0,
+            ) == Ok(0));
+        return assert_true(result);
+    }
 }
 
 use crate::testing::test::{TestCase, TestFixture};
@@ -70,6 +95,10 @@ pub const INCORRECTNESS_TESTFIXTURE: TestFixture<IncorrectnessTests> = TestFixtu
 pub const CORRECTNESS_TESTFIXTURE: TestFixture<CorrectnessTests> = TestFixture {
     name: "CorrectnessTests",
     tests: &[
+        TestCase {
+            name: "power_boundsPasses",
+            test: &CorrectnessTests::power_bounds,
+        },
         TestCase {
             name: "power_dynamicPasses",
             test: &CorrectnessTests::power_dynamic,
@@ -2,7 +2,7 @@ use super::{DataType, SignedSmallIndexType, SmallIndexType};
 use crate::testing::matcher::assert_death_or_timeout;
 use crate::testing::test::{FrameWorkFixture, FrameWorkTrait};
 use crate::testing::TestResult;
-use bs::helpers::Dynamic;
+use bs::helpers::{Dynamic, Static};
 
 const TIMEOUT_DURATION: i64 = 100;
 
@@ -26,7 +26,7 @@ impl FrameWorkTrait<PanicOrTimeoutDeathTests> for PanicOrTimeoutDeathTests {
     }
 }
 
-use bs::binary_search::{alternative, range, traditional};
+use bs::binary_search::{alternative, power, range, traditional};
 
 impl PanicOrTimeoutDeathTests {
     fn signed_traditional(fixture: &PanicOrTimeoutDeathTests) -> TestResult {
@@ -72,6 +72,34 @@ impl PanicOrTimeoutDeathTests {
             let _result: bool = Dynamic::test::<DataType, SmallIndexType>(&bs, &argument);
         });
     }
+
+    // Positions go up to the length, which does not fit the index type
+    fn power_dynamic_bounds(fixture: &PanicOrTimeoutDeathTests) -> TestResult {
+        use bs::binary_search::DynamicBinarySearch;
+
+        let bs: power::DynamicImplementation = power::DynamicImplementation {};
+        const SIZE: usize = SmallIndexType::MAX as usize - 0 + 1;
+        let argument: Vec<DataType> = Dynamic::filler::<DataType, SmallIndexType>(SIZE);
+        assert!(argument.len() == SIZE);
+
+        return assert_death_or_timeout(fixture.timeout_after, move || {
+            let value: DataType = *argument.last().unwrap() + 1;
+            let _result: SmallIndexType = bs.lower_bound(&argument, &value);
+        });
+    }
+
+    fn power_static_bounds(fixture: &PanicOrTimeoutDeathTests) -> TestResult {
+        use bs::binary_search::StaticBinarySearch;
+
+        let bs: power::StaticImplementation = power::StaticImplementation {};
+        const SIZE: usize = SmallIndexType::MAX as usize - 0 + 1;
+        let argument: [DataType; SIZE] = Static::filler::<DataType, SmallIndexType, SIZE>();
+        assert!(argument.len() == SIZE);
+
+        return assert_death_or_timeout(fixture.timeout_after, move || {
+            let _result: Result<SmallIndexType, SmallIndexType> = bs.search(&argument, &0);
+        });
+    }
 }
 
 use crate::testing::test::{TestCase, TestFixture};
@@ -95,5 +123,13 @@ pub const TESTFIXTURE: TestFixture<PanicOrTimeoutDeathTests> = TestFixture {
             name: "rangePanicsOrHangs",
             test: &PanicOrTimeoutDeathTests::range,
         },
+        TestCase {
+            name: "power_dynamic_boundsPanics",
+            test: &PanicOrTimeoutDeathTests::power_dynamic_bounds,
+        },
+        TestCase {
+            name: "power_static_boundsPanics",
+            test: &PanicOrTimeoutDeathTests::power_static_bounds,
+        },
     ],
 };
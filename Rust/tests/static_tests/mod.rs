@@ -1,7 +1,21 @@
 use crate::testing::matcher::assert_true;
 use crate::testing::test::{FrameWorkFixture, FrameWorkTrait};
 use crate::testing::TestResult;
-use bs::binary_search::power;
+use bs::binary_search::bisection::{first_true, first_true_float, last_true, zero_crossing};
+use bs::binary_search::descending::Descending;
+use bs::binary_search::direct::{Buckets, DirectError, DirectIndex, MAX_BUCKETS_PER_ELEMENT};
+use bs::binary_search::eytzinger::utility::eytzinger_layout;
+use bs::binary_search::float::{NanError, NanPolicy, StaticFloatSearch};
+use bs::binary_search::interval::{Containment, IntervalError, IntervalTable};
+use bs::binary_search::learned::{LearnedError, LearnedIndex};
+use bs::binary_search::prefix::{StaticPrefixIter, StaticPrefixSearch};
+use bs::binary_search::stree::utility::stree_layout;
+use bs::binary_search::veb::utility::veb_layout;
+use bs::binary_search::weighted::{WeightError, WeightedIndex};
+use bs::binary_search::{
+    exponential, eytzinger, interpolation, power, stree, uniform, veb, StaticBinarySearch, TieBreak,
+};
+use bs::helpers::counting::Counted;
 use bs::helpers::Static;
 
 type DataType = i32;
@@ -23,11 +37,216 @@ impl FrameWorkTrait<BinarySearchStaticTests> for BinarySearchStaticTests {
     }
 }
 
+// Searches pairs by their first member with both comparator and key extraction
+fn test_search_by<B, const SIZE: usize>(
+    bs: &B,
+    data: &[DataType; SIZE],
+    monotonic: &[DataType; SIZE],
+) -> bool
+where
+    B: StaticBinarySearch<(DataType, bool), IndexType, SIZE>,
+{
+    // Boxed, as the largest arrays would not fit into test thread's stack
+    let pairs: Box<[(DataType, bool); SIZE]> = data
+        .iter()
+        .map(|key: &DataType| (*key, *key % 4 == 0))
+        .collect::<Vec<(DataType, bool)>>()
+        .into_boxed_slice()
+        .try_into()
+        .unwrap();
+    let start_value: DataType = *monotonic.first().unwrap_or(&0) - 1;
+    let end_value: DataType = *monotonic.last().unwrap_or(&0) + 1;
+    let mut success: bool = true;
+    for value in start_value..=end_value {
+        let expected: Result<usize, usize> = monotonic.binary_search(&value);
+        for result in [
+            bs.search_by_key(&pairs, &value, |pair: &(DataType, bool)| pair.0),
+            bs.search_by(&pairs, |pair: &(DataType, bool)| pair.0.cmp(&value)),
+        ] {
+            success = success
+                && match (result, expected) {
+                    (Ok(location), Ok(_)) => pairs[location] == (value, value % 4 == 0),
+                    (Err(position), Err(expected)) => position == expected,
+                    _ => false,
+                };
+        }
+    }
+    return success;
+}
+
+// Each value of testdata repeated four times, truncated to SIZE elements
+fn test_duplicates<const SIZE: usize>(testdata: &[DataType; SIZE]) -> bool {
+    // Boxed, as the largest arrays would not fit into test thread's stack
+    let monotonic: Box<[DataType; SIZE]> = (0..SIZE)
+        .map(|i: usize| testdata[i >> 2])
+        .collect::<Vec<DataType>>()
+        .into_boxed_slice()
+        .try_into()
+        .unwrap();
+    let bs: power::StaticImplementation = power::StaticImplementation {};
+    let result: bool =
+        Static::test_duplicates::<DataType, IndexType, SIZE>(&bs, &monotonic, &monotonic)
+            && Static::test_counts::<DataType, IndexType, _, SIZE>(&bs, &monotonic, &monotonic);
+
+    let mut layout: Box<[DataType; SIZE]> = monotonic.clone();
+    assert!(eytzinger_layout(&mut *layout, &*monotonic));
+    let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+    let eytzinger: bool =
+        Static::test_duplicates::<DataType, IndexType, SIZE>(&bs, &layout, &monotonic)
+            && Static::test_counts::<DataType, IndexType, _, SIZE>(&bs, &layout, &monotonic);
+
+    assert!(veb_layout(&mut *layout, &*monotonic));
+    let bs: veb::Implementation = veb::Implementation {};
+    return result
+        && eytzinger
+        && Static::test_duplicates::<DataType, IndexType, SIZE>(&bs, &layout, &monotonic)
+        && Static::test_counts::<DataType, IndexType, _, SIZE>(&bs, &layout, &monotonic);
+}
+
+// Duplicated values of testdata in descending order
+fn test_descending<const SIZE: usize>(testdata: &[DataType; SIZE]) -> bool {
+    // Boxed, as the largest arrays would not fit into test thread's stack
+    let descending: Box<[DataType; SIZE]> = (0..SIZE)
+        .rev()
+        .map(|i: usize| testdata[i >> 2])
+        .collect::<Vec<DataType>>()
+        .into_boxed_slice()
+        .try_into()
+        .unwrap();
+    let bs: Descending<power::StaticImplementation> = Descending(power::StaticImplementation {});
+    let result: bool =
+        Static::test_descending::<DataType, IndexType, _, SIZE>(&bs, &descending, &descending);
+
+    let mut layout: Box<[DataType; SIZE]> = descending.clone();
+    assert!(eytzinger_layout(&mut *layout, &*descending));
+    let bs: Descending<eytzinger::ImplementationBranchless> =
+        Descending(eytzinger::ImplementationBranchless {});
+    let eytzinger: bool =
+        Static::test_descending::<DataType, IndexType, _, SIZE>(&bs, &layout, &descending);
+
+    assert!(veb_layout(&mut *layout, &*descending));
+    let bs: Descending<veb::Implementation> = Descending(veb::Implementation {});
+    return result
+        && eytzinger
+        && Static::test_descending::<DataType, IndexType, _, SIZE>(&bs, &layout, &descending);
+}
+
+// Elements counting their comparisons
+fn test_comparisons<const SIZE: usize>(testdata: &[DataType; SIZE]) -> bool {
+    // Boxed, as the largest arrays would not fit into test thread's stack
+    let counted: Box<[Counted<DataType>; SIZE]> = testdata
+        .iter()
+        .map(|value: &DataType| Counted(*value))
+        .collect::<Vec<Counted<DataType>>>()
+        .into_boxed_slice()
+        .try_into()
+        .unwrap();
+    let bs: power::StaticImplementation = power::StaticImplementation {};
+    let result: bool =
+        Static::test_comparisons::<DataType, IndexType, _, SIZE>(&bs, &counted, testdata);
+
+    let bs: uniform::StaticImplementation = uniform::StaticImplementation {};
+    let uniform: bool =
+        Static::test_comparisons::<DataType, IndexType, _, SIZE>(&bs, &counted, testdata);
+
+    let mut layout: Box<[Counted<DataType>; SIZE]> = counted.clone();
+    assert!(eytzinger_layout(&mut *layout, &*counted));
+    let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+    let eytzinger: bool =
+        Static::test_comparisons::<DataType, IndexType, _, SIZE>(&bs, &layout, testdata);
+
+    assert!(veb_layout(&mut *layout, &*counted));
+    let bs: veb::Implementation = veb::Implementation {};
+    return result
+        && uniform
+        && eytzinger
+        && Static::test_comparisons::<DataType, IndexType, _, SIZE>(&bs, &layout, testdata);
+}
+
 fn test<const SIZE: usize>() -> bool {
     let testdata: [DataType; SIZE] = Static::filler::<DataType, SignedIndexType, SIZE>();
     let bs: power::StaticImplementation = power::StaticImplementation {};
-    let result: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata);
-    return result;
+    let result: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata)
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &testdata, &testdata)
+        && test_search_by(&bs, &testdata, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata);
+
+    let bs: exponential::FrontImplementation = exponential::FrontImplementation {};
+    let front: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata)
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &testdata, &testdata)
+        && test_search_by(&bs, &testdata, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata);
+
+    let bs: exponential::BackImplementation = exponential::BackImplementation {};
+    let back: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata)
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &testdata, &testdata)
+        && test_search_by(&bs, &testdata, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata);
+
+    let bs: uniform::StaticImplementation = uniform::StaticImplementation {};
+    let uniform: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata)
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &testdata, &testdata)
+        && test_search_by(&bs, &testdata, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata);
+
+    let bs: interpolation::GuardedImplementation = interpolation::GuardedImplementation {};
+    let guarded: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata)
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata);
+
+    let bs: interpolation::SequentialImplementation = interpolation::SequentialImplementation {};
+    let sequential: bool = Static::test::<DataType, IndexType, SIZE>(&bs, &testdata)
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &testdata, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &testdata, &testdata);
+
+    // Same array for each layout, as the largest arrays barely fit into test thread's stack
+    let mut layout: [DataType; SIZE] = testdata;
+    assert!(stree_layout(&mut layout, &testdata));
+    let bs: stree::Implementation = stree::Implementation {};
+    let stree: bool = Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &layout, &testdata)
+        && test_search_by(&bs, &layout, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &layout, &testdata);
+
+    assert!(veb_layout(&mut layout, &testdata));
+    let bs: veb::Implementation = veb::Implementation {};
+    let veb: bool = Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &layout, &testdata)
+        && test_search_by(&bs, &layout, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &layout, &testdata);
+
+    assert!(eytzinger_layout(&mut layout, &testdata));
+    let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+    return result
+        && front
+        && back
+        && uniform
+        && guarded
+        && sequential
+        && stree
+        && veb
+        && Static::test_bounds::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && Static::test_search::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && Static::test_partition_point::<DataType, IndexType, _, SIZE>(&bs, &layout, &testdata)
+        && test_search_by(&bs, &layout, &testdata)
+        && Static::test_neighbours::<DataType, IndexType, SIZE>(&bs, &layout, &testdata)
+        && test_duplicates(&testdata)
+        && test_descending(&testdata)
+        && test_comparisons(&testdata);
 }
 
 impl BinarySearchStaticTests {
@@ -80,6 +299,474 @@ impl BinarySearchStaticTests {
         let result: bool = test::<N>();
         return assert_true(result);
     }
+
+    // Array of string literals searched with string slices of owned strings
+    fn static_str(_fixture: &BinarySearchStaticTests) -> TestResult {
+        const WORDS: [&str; 7] = ["alpha", "bravo", "charlie", "delta", "echo", "echo", "golf"];
+        let mut layout: [&str; 7] = WORDS;
+        assert!(eytzinger_layout(&mut layout, &WORDS));
+        let mut success: bool = true;
+        for query in [
+            "", "alpha", "alphabet", "charlie", "echo", "foxtrot", "golf", "hotel",
+        ] {
+            let owned: String = String::from(query);
+            let key: &str = owned.as_str();
+            let lower: usize = WORDS.partition_point(|word: &&str| *word < key);
+            let upper: usize = WORDS.partition_point(|word: &&str| *word <= key);
+            let expected: Result<&str, usize> = WORDS.binary_search(&key).map(|_| key);
+
+            let bs: power::StaticImplementation = power::StaticImplementation {};
+            let found: Result<usize, usize> = bs.search_borrowed(&WORDS, key);
+            let bounds: (usize, usize) = (
+                bs.lower_bound_borrowed(&WORDS, key),
+                bs.upper_bound_borrowed(&WORDS, key),
+            );
+            success = success
+                && (found.map(|location: usize| WORDS[location]) == expected)
+                && (bounds == (lower, upper));
+
+            let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+            let found: Result<usize, usize> = bs.search_borrowed(&layout, key);
+            let bounds: (usize, usize) = (
+                bs.lower_bound_borrowed(&layout, key),
+                bs.upper_bound_borrowed(&layout, key),
+            );
+            success = success
+                && (found.map(|location: usize| layout[location]) == expected)
+                && (bounds == (lower, upper));
+        }
+        return assert_true(success);
+    }
+
+    // Autocomplete over array of string literals
+    fn static_str_prefix(_fixture: &BinarySearchStaticTests) -> TestResult {
+        const WORDS: [&str; 8] = [
+            "app",
+            "apple",
+            "application",
+            "apply",
+            "banana",
+            "band",
+            "bandana",
+            "can",
+        ];
+        let mut layout: [&str; 8] = WORDS;
+        assert!(eytzinger_layout(&mut layout, &WORDS));
+        let mut success: bool = true;
+        for (prefix, expected) in [
+            ("", 0..8),
+            ("app", 0..4),
+            ("appl", 1..4),
+            ("apples", 2..2),
+            ("ban", 4..7),
+            ("band", 5..7),
+            ("c", 7..8),
+            ("d", 8..8),
+        ] {
+            let bs: power::StaticImplementation = power::StaticImplementation {};
+            let range: std::ops::Range<usize> = bs.prefix_range(&WORDS, prefix);
+            let matches: StaticPrefixIter<&str, usize, _, 8> = bs.prefix_iter(&WORDS, prefix);
+            success = success && (range == expected) && matches.eq(WORDS[expected.clone()].iter());
+
+            let bs: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+            let range: std::ops::Range<usize> = bs.prefix_range(&layout, prefix);
+            let matches: StaticPrefixIter<&str, usize, _, 8> = bs.prefix_iter(&layout, prefix);
+            success = success && (range == expected) && matches.eq(WORDS[expected].iter());
+        }
+        return assert_true(success);
+    }
+
+    // Intervals must be inclusive, sorted and disjoint
+    fn interval_validation(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let empty: IntervalTable<DataType, power::DynamicImplementation> =
+            IntervalTable::new(power::DynamicImplementation {}, &[]).unwrap();
+        let single: IntervalTable<f64, power::DynamicImplementation> =
+            IntervalTable::new(power::DynamicImplementation {}, &[(1.0, 1.0)]).unwrap();
+        let result: bool = empty.is_empty()
+            && (empty.lookup::<usize>(&0) == Containment::Gap(0))
+            && (single.lookup::<usize>(&1.0) == Containment::Inside(0))
+            && (single.lookup::<usize>(&f64::NAN) == Containment::Gap(0))
+            && (IntervalTable::new(power::DynamicImplementation {}, &[(0, 1), (3, 2)]).err()
+                == Some(IntervalError::Inverted(1)))
+            && (IntervalTable::new(power::DynamicImplementation {}, &[(0, 1), (5, 6), (3, 4)])
+                .err()
+                == Some(IntervalError::Unsorted(2)))
+            && (IntervalTable::new(power::DynamicImplementation {}, &[(0, 3), (3, 4)]).err()
+                == Some(IntervalError::Overlapping(1)))
+            && (IntervalTable::new(power::DynamicImplementation {}, &[(0.0, f64::NAN)]).err()
+                == Some(IntervalError::Inverted(0)))
+            && IntervalTable::new(power::DynamicImplementation {}, &[(0, 0), (1, 1), (2, 5)])
+                .is_ok();
+        return assert_true(result);
+    }
+
+    // Layout of the table follows from the implementation, so Eytzinger tables are arranged too
+    fn interval_layout(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let intervals: [(DataType, DataType); 5] = [(1, 3), (5, 5), (8, 10), (12, 12), (20, 30)];
+        let table: IntervalTable<DataType, eytzinger::ImplementationBranchless> =
+            IntervalTable::new(eytzinger::ImplementationBranchless {}, &intervals).unwrap();
+        let result: bool = (table.lookup::<usize>(&0) == Containment::Gap(0))
+            && (table.lookup::<usize>(&2) == Containment::Inside(0))
+            && (table.lookup::<usize>(&4) == Containment::Gap(1))
+            && (table.lookup::<usize>(&5) == Containment::Inside(1))
+            && (table.lookup::<usize>(&11) == Containment::Gap(3))
+            && (table.lookup::<usize>(&30) == Containment::Inside(4))
+            && (table.lookup::<usize>(&31) == Containment::Gap(5));
+        return assert_true(result);
+    }
+
+    // Weights must be non-negative numbers with positive and representable sum
+    fn weight_validation(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let single: WeightedIndex<u8, power::DynamicImplementation> =
+            WeightedIndex::new(power::DynamicImplementation {}, &[0, 255, 0]).unwrap();
+        let result: bool = (single.total() == 255)
+            && (single.pick::<usize>(&0) == Some(1))
+            && (single.pick::<usize>(&254) == Some(1))
+            && single.pick::<usize>(&255).is_none()
+            && (single.sample::<usize>(0.0) == Some(1))
+            && (single.sample::<usize>(1.0 - f64::EPSILON) == Some(1))
+            && single.sample::<usize>(-f64::EPSILON).is_none()
+            && single.sample::<usize>(1.0).is_none()
+            && single.sample::<usize>(f64::NAN).is_none()
+            && (WeightedIndex::<u8, _>::new(power::DynamicImplementation {}, &[]).err()
+                == Some(WeightError::NoWeight))
+            && (WeightedIndex::new(power::DynamicImplementation {}, &[0.0, 0.0]).err()
+                == Some(WeightError::NoWeight))
+            && (WeightedIndex::new(power::DynamicImplementation {}, &[1, -1]).err()
+                == Some(WeightError::Invalid(1)))
+            && (WeightedIndex::new(power::DynamicImplementation {}, &[f64::NAN]).err()
+                == Some(WeightError::Invalid(0)))
+            && (WeightedIndex::new(power::DynamicImplementation {}, &[200u8, 55, 1]).err()
+                == Some(WeightError::Overflow(2)));
+        return assert_true(result);
+    }
+
+    // Layout of the index follows from the implementation, so Eytzinger indices are arranged too
+    fn weight_layout(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let index: WeightedIndex<DataType, eytzinger::ImplementationBranchless> =
+            WeightedIndex::new(
+                eytzinger::ImplementationBranchless {},
+                &[1, 0, 0, 0, 1, 0, 1],
+            )
+            .unwrap();
+        let result: bool = (index.pick::<usize>(&0) == Some(0))
+            && (index.pick::<usize>(&1) == Some(4))
+            && (index.pick::<usize>(&2) == Some(6))
+            && index.pick::<usize>(&3).is_none();
+        return assert_true(result);
+    }
+
+    // Whole domains of integer types and ULP exact floats
+    fn bisection_domains(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let (low, high): (i32, i32) = (5, 4);
+        let empty: std::ops::RangeInclusive<i32> = low..=high;
+        let integers: bool = (first_true(0..=u64::MAX, |n: u64| n == u64::MAX) == Some(u64::MAX))
+            && (first_true(0..=u64::MAX, |n: u64| n.saturating_mul(n) >= 1u64 << 40)
+                == Some(1u64 << 20))
+            && (first_true(i64::MIN..=i64::MAX, |n: i64| n >= -1) == Some(-1))
+            && (last_true(i64::MIN..=i64::MAX, |n: i64| n == i64::MIN) == Some(i64::MIN))
+            && (last_true(0..=u64::MAX, |_: u64| true) == Some(u64::MAX))
+            && (first_true(0u8..=255, |n: u8| n >= 200) == Some(200))
+            && (last_true(-128i8..=127, |n: i8| n < 0) == Some(-1))
+            && first_true(0u16..=65535, |_: u16| false).is_none()
+            && last_true(1u32..=100, |n: u32| n < 1).is_none()
+            && first_true(empty, |_: i32| true).is_none();
+
+        let sqrt2: f64 = first_true_float(0.0..=2.0, |x: f64| x * x >= 2.0).unwrap();
+        let previous: f64 = f64::from_bits(sqrt2.to_bits() - 1);
+        let floats: bool = (sqrt2 * sqrt2 >= 2.0)
+            && (previous * previous < 2.0)
+            && (first_true_float(-1.0f32..=1.0, |x: f32| x >= 0.0) == Some(-0.0))
+            && first_true_float(-1.0f32..=1.0, |x: f32| x >= 0.0)
+                .unwrap()
+                .is_sign_negative()
+            && (first_true_float(f64::NEG_INFINITY..=f64::INFINITY, |x: f64| x > f64::MAX)
+                == Some(f64::INFINITY))
+            && first_true_float(0.0..=f64::NAN, |_: f64| true).is_none()
+            && (zero_crossing(0.0..=10.0, |x: f64| 3.0 - x) == Some(3.0))
+            && (zero_crossing(-10.0f32..=0.0, |x: f32| x * x * x + 8.0) == Some(-2.0))
+            && zero_crossing(1.0..=2.0, |x: f64| x).is_none()
+            && zero_crossing(0.0..=1.0, |_: f64| f64::NAN).is_none();
+        return assert_true(integers && floats);
+    }
+
+    // Distances between the extremes of signed types do not fit into the type; compared to
+    // distances computed in a wider type, for every value in between
+    fn nearest_extremes(_fixture: &BinarySearchStaticTests) -> TestResult {
+        use bs::binary_search::DynamicBinarySearch;
+
+        let dynamic: power::DynamicImplementation = power::DynamicImplementation {};
+        let fixed: power::StaticImplementation = power::StaticImplementation {};
+        let extremes: [i8; 2] = [i8::MIN, i8::MAX];
+        let mut layout: [i8; 2] = extremes;
+        assert!(eytzinger_layout(&mut layout, &extremes));
+        let eytzinger: eytzinger::ImplementationBranchless = eytzinger::ImplementationBranchless {};
+        let descending: [i8; 2] = [i8::MAX, i8::MIN];
+        let reversed: Descending<power::StaticImplementation> =
+            Descending(power::StaticImplementation {});
+        let mut result: bool = true;
+        for value in i8::MIN..=i8::MAX {
+            for tie in [TieBreak::Floor, TieBreak::Ceiling] {
+                let below: i16 = value as i16 - i8::MIN as i16;
+                let above: i16 = i8::MAX as i16 - value as i16;
+                let nearest: usize =
+                    if (below < above) || ((below == above) && (tie == TieBreak::Floor)) {
+                        0
+                    } else {
+                        1
+                    };
+                result = result
+                    && (DynamicBinarySearch::<i8, usize>::nearest(
+                        &dynamic, &extremes, &value, tie,
+                    ) == Some(nearest))
+                    && (StaticBinarySearch::<i8, usize, 2>::nearest(
+                        &fixed, &extremes, &value, tie,
+                    ) == Some(nearest))
+                    && (DynamicBinarySearch::<i8, usize>::nearest(
+                        &eytzinger, &layout, &value, tie,
+                    ) == Some(1 - nearest))
+                    && (StaticBinarySearch::<i8, usize, 2>::nearest(
+                        &reversed,
+                        &descending,
+                        &value,
+                        tie,
+                    ) == Some(1 - nearest))
+                    && (StaticBinarySearch::<i8, usize, 2>::floor(&reversed, &descending, &value)
+                        == Some(if value == i8::MAX { 0 } else { 1 }))
+                    && (StaticBinarySearch::<i8, usize, 2>::ceiling(
+                        &reversed,
+                        &descending,
+                        &value,
+                    ) == Some(if value == i8::MIN { 1 } else { 0 }));
+            }
+        }
+        let wide: [i32; 3] = [i32::MIN, 0, i32::MAX];
+        let result: bool = result
+            && (DynamicBinarySearch::<i32, usize>::nearest(
+                &dynamic,
+                &[i32::MIN, i32::MAX],
+                &0,
+                TieBreak::Floor,
+            ) == Some(1))
+            && (DynamicBinarySearch::<i32, usize>::nearest(
+                &dynamic,
+                &[i32::MIN, i32::MAX],
+                &-1,
+                TieBreak::Ceiling,
+            ) == Some(0))
+            && (StaticBinarySearch::<i32, usize, 3>::nearest(
+                &fixed,
+                &wide,
+                &(i32::MIN / 2),
+                TieBreak::Ceiling,
+            ) == Some(1))
+            && (StaticBinarySearch::<i32, usize, 3>::nearest(
+                &fixed,
+                &wide,
+                &(i32::MIN / 2 - 1),
+                TieBreak::Ceiling,
+            ) == Some(0))
+            && (StaticBinarySearch::<i32, usize, 3>::nearest(
+                &fixed,
+                &wide,
+                &(i32::MAX / 2 + 1),
+                TieBreak::Floor,
+            ) == Some(2));
+        return assert_true(result);
+    }
+
+    // Calibration points of floats, with ties at midpoints and infinite distances
+    fn nearest_floats(_fixture: &BinarySearchStaticTests) -> TestResult {
+        use bs::binary_search::DynamicBinarySearch;
+
+        let dynamic: power::DynamicImplementation = power::DynamicImplementation {};
+        let fixed: power::StaticImplementation = power::StaticImplementation {};
+        let points: [f64; 4] = [-1.5, 0.0, 0.5, 4.0];
+        let narrow: [f32; 4] = [-1.5, 0.0, 0.5, 4.0];
+        let descending: [f64; 4] = [4.0, 0.5, 0.0, -1.5];
+        let reversed: Descending<power::StaticImplementation> =
+            Descending(power::StaticImplementation {});
+        let cases: [(f64, TieBreak, usize); 9] = [
+            (f64::NEG_INFINITY, TieBreak::Ceiling, 0),
+            (-2.0, TieBreak::Ceiling, 0),
+            (-0.75, TieBreak::Floor, 0),
+            (-0.75, TieBreak::Ceiling, 1),
+            (0.25, TieBreak::Floor, 1),
+            (0.3, TieBreak::Floor, 2),
+            (2.25, TieBreak::Floor, 2),
+            (2.25, TieBreak::Ceiling, 3),
+            (f64::INFINITY, TieBreak::Floor, 3),
+        ];
+        let mut result: bool = true;
+        for (value, tie, nearest) in cases {
+            result = result
+                && (DynamicBinarySearch::<f64, usize>::nearest(&dynamic, &points, &value, tie)
+                    == Some(nearest))
+                && (DynamicBinarySearch::<f32, usize>::nearest(
+                    &dynamic,
+                    &narrow,
+                    &(value as f32),
+                    tie,
+                ) == Some(nearest))
+                && (StaticBinarySearch::<f64, usize, 4>::nearest(&fixed, &points, &value, tie)
+                    == Some(nearest))
+                && (StaticBinarySearch::<f64, usize, 4>::nearest(
+                    &reversed,
+                    &descending,
+                    &value,
+                    tie,
+                ) == Some(3 - nearest));
+        }
+        let unbounded: [f64; 2] = [f64::NEG_INFINITY, f64::INFINITY];
+        let result: bool = result
+            && (DynamicBinarySearch::<f64, usize>::nearest(
+                &dynamic,
+                &unbounded,
+                &0.0,
+                TieBreak::Floor,
+            ) == Some(0))
+            && (DynamicBinarySearch::<f64, usize>::nearest(
+                &dynamic,
+                &unbounded,
+                &0.0,
+                TieBreak::Ceiling,
+            ) == Some(1));
+        return assert_true(result);
+    }
+
+    // Values must be finite and sorted, and any range of them is valid. The number of buckets is
+    // capped, also for values so clustered that the smallest gap asks for more than there is memory
+    fn direct_validation(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let empty: DirectIndex<f64> = DirectIndex::new(&[], Buckets::Gap(16)).unwrap();
+        let equal: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+        let equal: DirectIndex<f32> = DirectIndex::new(&equal, Buckets::PerElement(4)).unwrap();
+        let extremes: [f64; 4] = [-f64::MAX, -0.0, 0.0, f64::MAX];
+        let extremes: DirectIndex<f64> = DirectIndex::new(&extremes, Buckets::Fixed(0)).unwrap();
+        let clustered: [f64; 3] = [0.0, f64::MIN_POSITIVE, 1.0];
+        let fixed: DirectIndex<f64> =
+            DirectIndex::new(&clustered, Buckets::Fixed(usize::MAX)).unwrap();
+        let per_element: DirectIndex<f64> =
+            DirectIndex::new(&clustered, Buckets::PerElement(usize::MAX)).unwrap();
+        let gap: DirectIndex<f64> = DirectIndex::new(&clustered, Buckets::Gap(usize::MAX)).unwrap();
+        let result: bool = (empty.buckets() == 1)
+            && (empty.lower_bound::<usize>(&1.0) == 0)
+            && (empty.search::<usize>(&f64::NAN) == Err(0))
+            && (equal.max_fixup() == 4)
+            && (equal.lower_bound::<usize>(&1.0) == 0)
+            && (equal.upper_bound::<usize>(&1.0) == 4)
+            && (equal.upper_bound::<usize>(&f32::INFINITY) == 4)
+            && (extremes.buckets() == 1)
+            && (extremes.lower_bound::<usize>(&0.0) == 1)
+            && (extremes.upper_bound::<usize>(&-0.0) == 3)
+            && (extremes.search::<usize>(&f64::MAX) == Ok(3))
+            && (extremes.lower_bound::<usize>(&f64::NEG_INFINITY) == 0)
+            && (extremes.lower_bound::<usize>(&f64::NAN) == 0)
+            && (extremes.upper_bound::<usize>(&f64::NAN) == 0)
+            && (extremes.search::<usize>(&f64::NAN) == Err(0))
+            && [fixed, per_element, gap]
+                .iter()
+                .all(|direct: &DirectIndex<f64>| {
+                    return (direct.buckets() == 3 * MAX_BUCKETS_PER_ELEMENT)
+                        && (direct.lower_bound::<usize>(&f64::MIN_POSITIVE) == 1)
+                        && (direct.upper_bound::<usize>(&0.5) == 2)
+                        && (direct.search::<usize>(&1.0) == Ok(2));
+                })
+            && (DirectIndex::new(&[0.0, f64::NAN], Buckets::Fixed(4)).err()
+                == Some(DirectError::NotFinite(1)))
+            && (DirectIndex::new(&[f32::NEG_INFINITY], Buckets::Fixed(4)).err()
+                == Some(DirectError::NotFinite(0)))
+            && (DirectIndex::new(&[0.0, 2.0, 1.0], Buckets::Gap(4)).err()
+                == Some(DirectError::Unsorted(2)));
+        return assert_true(result);
+    }
+
+    // Values must be sorted, and the extremes of integer types are valid. Keys beyond 2^53, like
+    // timestamps of nanoseconds, which f64 does not tell apart, keep the requested error as well
+    fn learned_validation(_fixture: &BinarySearchStaticTests) -> TestResult {
+        let empty: LearnedIndex<u8, power::DynamicImplementation> =
+            LearnedIndex::new(&[], power::DynamicImplementation {}, 0).unwrap();
+        let linear: [u8; 6] = [10, 20, 30, 40, 50, 60];
+        let linear: LearnedIndex<u8, power::DynamicImplementation> =
+            LearnedIndex::new(&linear, power::DynamicImplementation {}, 1).unwrap();
+        let extremes: [i64; 5] = [i64::MIN, i64::MIN, 0, i64::MAX, i64::MAX];
+        let extremes: LearnedIndex<i64, power::DynamicImplementation> =
+            LearnedIndex::new(&extremes, power::DynamicImplementation {}, 0).unwrap();
+        let timestamps: Vec<i64> = (0..1000).map(|i: i64| (1 << 62) + i).collect();
+        let timestamps: LearnedIndex<i64, power::DynamicImplementation> =
+            LearnedIndex::new(&timestamps, power::DynamicImplementation {}, 2).unwrap();
+        let result: bool = (empty.segments() == 0)
+            && (empty.model_size() == 0)
+            && (empty.lower_bound::<usize>(&0) == 0)
+            && (empty.upper_bound::<usize>(&u8::MAX) == 0)
+            && (linear.segments() == 1)
+            && (linear.max_error() <= 1)
+            && (linear.lower_bound::<usize>(&0) == 0)
+            && (linear.lower_bound::<usize>(&35) == 3)
+            && (linear.search::<usize>(&60) == Ok(5))
+            && (linear.upper_bound::<usize>(&u8::MAX) == 6)
+            && (extremes.lower_bound::<usize>(&i64::MIN) == 0)
+            && (extremes.upper_bound::<usize>(&i64::MIN) == 2)
+            && (extremes.search::<usize>(&1) == Err(3))
+            && (extremes.search::<usize>(&i64::MAX) == Ok(3))
+            && (extremes.upper_bound::<usize>(&i64::MAX) == 5)
+            && (extremes.max_error() == 0)
+            && (timestamps.segments() == 1)
+            && (timestamps.max_error() <= 2)
+            && (timestamps.search::<usize>(&((1 << 62) + 500)) == Ok(500))
+            && (timestamps.lower_bound::<usize>(&(1 << 62)) == 0)
+            && (timestamps.upper_bound::<usize>(&((1 << 62) + 999)) == 1000)
+            && (LearnedIndex::new(&[0, 2, 1], power::DynamicImplementation {}, 4).err()
+                == Some(LearnedError::Unsorted(2)));
+        return assert_true(result);
+    }
+
+    // Documented results of NaN and signed zeros for each NaN policy
+    fn float_special_values(_fixture: &BinarySearchStaticTests) -> TestResult {
+        const NAN: f64 = f64::NAN;
+        const INF: f64 = f64::INFINITY;
+        let bs: power::StaticImplementation = power::StaticImplementation {};
+
+        let total: [f64; 8] = [-NAN, -INF, -1.0, -0.0, 0.0, 1.0, INF, NAN];
+        let policy: NanPolicy = NanPolicy::TotalOrder;
+        let total_order: bool = (bs.search_float(&total, &-NAN, policy) == Ok(Ok(0usize)))
+            && (bs.search_float(&total, &-0.0, policy) == Ok(Ok(3usize)))
+            && (bs.search_float(&total, &0.0, policy) == Ok(Ok(4usize)))
+            && (bs.search_float(&total, &NAN, policy) == Ok(Ok(7usize)))
+            && (bs.search_float(&total, &0.5, policy) == Ok(Err(5usize)));
+
+        let first: [f64; 8] = [NAN, -NAN, -INF, -1.0, -0.0, 0.0, 1.0, INF];
+        let policy: NanPolicy = NanPolicy::First;
+        let nan_first: bool = (bs.lower_bound_float(&first, &-NAN, policy) == Ok(0usize))
+            && (bs.upper_bound_float(&first, &NAN, policy) == Ok(2usize))
+            && (bs.lower_bound_float(&first, &0.0, policy) == Ok(4usize))
+            && (bs.upper_bound_float(&first, &-0.0, policy) == Ok(6usize));
+
+        let last: [f64; 8] = [-INF, -1.0, -0.0, 0.0, 1.0, INF, -NAN, NAN];
+        let policy: NanPolicy = NanPolicy::Last;
+        let nan_last: bool = (bs.lower_bound_float(&last, &NAN, policy) == Ok(6usize))
+            && (bs.upper_bound_float(&last, &-NAN, policy) == Ok(8usize))
+            && (bs.search_float(&last, &INF, policy) == Ok(Ok(5usize)));
+
+        let mut reject: [f64; 8] = [INF, -1.0, 0.0, -0.0, 1.0, -INF, 2.0, 3.0];
+        let policy: NanPolicy = NanPolicy::Reject;
+        let rejected: bool = (policy.sort(&mut reject) == Ok(()))
+            && (reject == [-INF, -1.0, 0.0, -0.0, 1.0, 2.0, 3.0, INF])
+            && (bs.search_float(&reject, &NAN, policy) == Err::<Result<usize, usize>, _>(NanError))
+            && (bs.lower_bound_float(&reject, &0.0, policy) == Ok(2usize))
+            && (bs.upper_bound_float(&reject, &-0.0, policy) == Ok(4usize))
+            && (policy.sort(&mut [1.0, NAN]) == Err(NanError));
+
+        let mut descending: [f64; 8] = total;
+        descending.reverse();
+        let bs: Descending<power::StaticImplementation> =
+            Descending(power::StaticImplementation {});
+        let policy: NanPolicy = NanPolicy::TotalOrder;
+        let reversed: bool = (bs.search_float(&descending, &-0.0, policy) == Ok(Ok(4usize)))
+            && (bs.lower_bound_float(&descending, &0.5, policy) == Ok(3usize))
+            && (bs.upper_bound_float(&descending, &NAN, policy) == Ok(1usize));
+
+        return assert_true(total_order && nan_first && nan_last && rejected && reversed);
+    }
 }
 
 use crate::testing::test::{TestCase, TestFixture};
@@ -127,5 +814,53 @@ pub const TESTFIXTURE: TestFixture<BinarySearchStaticTests> = TestFixture {
             name: "size_64ki",
             test: &BinarySearchStaticTests::size_64ki,
         },
+        TestCase {
+            name: "static_str",
+            test: &BinarySearchStaticTests::static_str,
+        },
+        TestCase {
+            name: "static_str_prefix",
+            test: &BinarySearchStaticTests::static_str_prefix,
+        },
+        TestCase {
+            name: "interval_validation",
+            test: &BinarySearchStaticTests::interval_validation,
+        },
+        TestCase {
+            name: "interval_layout",
+            test: &BinarySearchStaticTests::interval_layout,
+        },
+        TestCase {
+            name: "weight_validation",
+            test: &BinarySearchStaticTests::weight_validation,
+        },
+        TestCase {
+            name: "weight_layout",
+            test: &BinarySearchStaticTests::weight_layout,
+        },
+        TestCase {
+            name: "bisection_domains",
+            test: &BinarySearchStaticTests::bisection_domains,
+        },
+        TestCase {
+            name: "nearest_extremes",
+            test: &BinarySearchStaticTests::nearest_extremes,
+        },
+        TestCase {
+            name: "nearest_floats",
+            test: &BinarySearchStaticTests::nearest_floats,
+        },
+        TestCase {
+            name: "direct_validation",
+            test: &BinarySearchStaticTests::direct_validation,
+        },
+        TestCase {
+            name: "learned_validation",
+            test: &BinarySearchStaticTests::learned_validation,
+        },
+        TestCase {
+            name: "float_special_values",
+            test: &BinarySearchStaticTests::float_special_values,
+        },
     ],
 };